@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EmissionBudgetArgs {
+    pub epoch_duration: i64,
+    pub epoch_budget: u64,
+    pub lifetime_cap: u64,
+    pub scale_down: bool,
+}
@@ -5,5 +5,10 @@ pub mod initialize_user_args;
 pub mod initialize_band_args;
 pub mod initialize_token_mint_args;
 pub mod release;
+pub mod reward_config_args;
+pub mod emission_budget_args;
+pub mod reimbursement_config_args;
+pub mod program_metadata_args;
+pub mod withdraw_args;
 // pub mod release_nft;
 // pub mod release_access;
\ No newline at end of file
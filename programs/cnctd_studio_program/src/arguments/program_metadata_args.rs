@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateProgramMetadataArgs {
+    pub new_owner: Option<Pubkey>,
+    pub version: Option<u8>,
+    pub treasury_fee_bps: Option<u16>,
+}
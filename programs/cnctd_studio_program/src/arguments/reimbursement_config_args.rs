@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReimbursementConfigArgs {
+    pub max_per_tx: u64,
+    pub max_per_day: u64,
+}
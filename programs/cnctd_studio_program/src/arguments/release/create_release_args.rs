@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use super::SplitShare;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CreateReleaseArgs {
+    pub release_id: String,
+    pub price: u64,
+    pub split_shares: Vec<SplitShare>,
+    pub fee_compensation: Option<u64>,
+}
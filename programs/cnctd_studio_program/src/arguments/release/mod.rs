@@ -5,16 +5,41 @@ mod fulfill_args;
 mod complete_args;
 mod purchase_access_args;
 mod close_release_access_args;
+mod refund_escrow_args;
+mod reclaim_expired_escrow_args;
+mod create_release_args;
+mod update_release_args;
 
 pub use open_escrow_args::*;
 pub use fulfill_args::*;
 pub use complete_args::*;
 pub use purchase_access_args::*;
 pub use close_release_access_args::*;
+pub use refund_escrow_args::*;
+pub use reclaim_expired_escrow_args::*;
+pub use create_release_args::*;
+pub use update_release_args::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
 pub struct PaymentSplit {
     pub recipient_usdc_ata: Pubkey,  // ATA to receive payment
     pub recipient_cnctd_ata: Pubkey,  // ATA to receive CNCTD reward (if applicable)
     pub amount: u64,        // Pre-calculated amount in USDC lamports
-}
\ No newline at end of file
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct SplitShare {
+    pub recipient_usdc_ata: Pubkey,  // ATA to receive payment
+    pub recipient_cnctd_ata: Pubkey,  // ATA to receive CNCTD reward (if applicable)
+    pub share_bps: u16,      // Share of the price after treasury fee, in basis points
+    pub recipient: SplitRecipient, // Who the share pays
+}
+
+/// Who a split share pays. User and band recipients are checked against the registered
+/// account when the release terms are set; external recipients are taken as given.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, InitSpace)]
+pub enum SplitRecipient {
+    User { user_pda: Pubkey },
+    Band { band_pda: Pubkey },
+    External,
+}
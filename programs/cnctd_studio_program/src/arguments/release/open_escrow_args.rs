@@ -1,13 +1,10 @@
 use anchor_lang::prelude::*;
 
-use super::PaymentSplit;
-
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct OpenEscrowArgs {
     pub buyer_id: String,
     pub release_id: String,
-    pub treasury_fee: u64,
-    pub payment_splits: Vec<PaymentSplit>,
     pub purchase_date: i64,
+    pub fulfillment_window: Option<i64>, // Seconds until the escrow can be reclaimed, defaults to DEFAULT_ESCROW_FULFILLMENT_WINDOW
     pub fee_compensation: Option<u64>,
 }
\ No newline at end of file
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ReclaimExpiredEscrowArgs {
+    pub buyer_id: String,
+    pub release_id: String,
+}
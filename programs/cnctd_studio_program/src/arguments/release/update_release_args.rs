@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+use super::SplitShare;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UpdateReleaseArgs {
+    pub release_id: String,
+    pub price: Option<u64>,
+    pub treasury_fee_bps: Option<u16>, // Treasurer only, overrides the platform fee for this release
+    pub split_shares: Option<Vec<SplitShare>>,
+    pub active: Option<bool>,
+    pub new_authority: Option<Pubkey>,
+}
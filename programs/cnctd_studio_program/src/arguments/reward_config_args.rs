@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RewardConfigArgs {
+    pub enabled: bool,
+    pub buyer_rate_bps: u32,
+    pub artist_rate_bps: u32,
+    pub buyer_cap_per_tx: u64,
+    pub artist_cap_per_tx: u64,
+}
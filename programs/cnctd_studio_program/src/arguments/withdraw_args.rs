@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct WithdrawArgs {
+    pub user_id: String,
+    pub amount: u64,
+}
@@ -1,3 +1,20 @@
 pub const MAX_ALBUMS_PER_ARTIST: u8 = 10;
 pub const MAX_CREDIT_SPLITS: u8 = 5;
 pub const PROGRAM_VERSION: &str = "0.1.0";
+pub const MAX_ADMINS: usize = 10;
+
+// Escrow fulfillment windows (seconds)
+pub const DEFAULT_ESCROW_FULFILLMENT_WINDOW: i64 = 7 * 24 * 60 * 60;
+pub const MIN_ESCROW_FULFILLMENT_WINDOW: i64 = 60 * 60;
+pub const MAX_ESCROW_FULFILLMENT_WINDOW: i64 = 30 * 24 * 60 * 60;
+
+pub const BASIS_POINTS: u64 = 10_000;
+pub const MAX_PAYMENT_SPLITS: usize = 10;
+
+pub const MAX_BAND_MEMBERS: usize = 16;
+
+// Longest id stored in an account, a UUID with hyphens
+pub const MAX_ID_LEN: usize = 36;
+
+// Upper bound on the queue delay for admin changes (seconds)
+pub const MAX_ADMIN_CHANGE_DELAY: i64 = 30 * 24 * 60 * 60;
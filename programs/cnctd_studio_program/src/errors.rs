@@ -88,4 +88,121 @@ pub enum CnctdStudioError {
 
     #[msg("NFT not minted")]
     NFTNotMinted,
-}
+
+    #[msg("Escrow has not expired yet")]
+    EscrowNotExpired,
+
+    #[msg("Fulfillment window is out of range")]
+    InvalidFulfillmentWindow,
+
+    #[msg("Release is not available for purchase")]
+    ReleaseInactive,
+
+    #[msg("Treasury fee must not exceed 100%")]
+    InvalidTreasuryFee,
+
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+
+    #[msg("Emission budget exceeded")]
+    EmissionBudgetExceeded,
+
+    #[msg("Maximum number of admins reached")]
+    TooManyAdmins,
+
+    #[msg("Cannot remove the last super admin")]
+    CannotRemoveLastSuperAdmin,
+
+    #[msg("This operation requires an approved multisig proposal")]
+    MultisigApprovalRequired,
+
+    #[msg("Approval threshold must be between 1 and the number of admins")]
+    InvalidThreshold,
+
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Admin has already approved this proposal")]
+    AlreadyApproved,
+
+    #[msg("Proposal does not have enough approvals")]
+    InsufficientApprovals,
+
+    #[msg("Accounts do not match the proposal")]
+    ProposalAccountMismatch,
+
+    #[msg("Admin changes must be queued through a timelocked proposal")]
+    AdminChangeQueued,
+
+    #[msg("Timelock delay is out of range")]
+    InvalidTimelockDelay,
+
+    #[msg("Timelock has not elapsed yet")]
+    TimelockNotElapsed,
+
+    #[msg("Proposal has been cancelled")]
+    ProposalCancelled,
+
+    #[msg("Fee reimbursement exceeds the configured limit")]
+    ReimbursementLimitExceeded,
+
+    #[msg("This part of the program is paused")]
+    ProgramPaused,
+
+    #[msg("Escrow account does not match the release and buyer")]
+    InvalidEscrowAccount,
+
+    #[msg("Release access account does not match the release and buyer")]
+    InvalidReleaseAccessAccount,
+
+    #[msg("User account does not match the user id")]
+    InvalidUserAccount,
+
+    #[msg("Band account does not match the band id")]
+    InvalidBandAccount,
+
+    #[msg("Account type does not support migration")]
+    UnsupportedAccountType,
+
+    #[msg("Account is already on the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Id exceeds the maximum length of 36 characters")]
+    IdTooLong,
+
+    #[msg("Too many payment splits")]
+    TooManyPaymentSplits,
+
+    #[msg("Funds must go to the user's custody account or their bound wallet")]
+    CustodyDestinationRequired,
+
+    #[msg("User is already a member of this band")]
+    BandMemberExists,
+
+    #[msg("User is not a member of this band")]
+    BandMemberNotFound,
+
+    #[msg("Band has too many members")]
+    TooManyBandMembers,
+
+    #[msg("Split recipient does not match its registered user or band")]
+    InvalidSplitRecipient,
+
+    #[msg("An access record for this release and buyer already exists under legacy seeds")]
+    LegacyReleaseAccessExists,
+
+    #[msg("User already exists under legacy seeds")]
+    LegacyUserExists,
+
+    #[msg("Band is still a split recipient of one or more releases")]
+    BandStillReferenced,
+
+    #[msg("Band account must be migrated to the current layout first")]
+    BandNotMigrated,
+
+    #[msg("Band already exists under legacy seeds")]
+    LegacyBandExists,
+
+    #[msg("An escrow for this release and buyer already exists under legacy seeds")]
+    LegacyEscrowExists,
+}
\ No newline at end of file
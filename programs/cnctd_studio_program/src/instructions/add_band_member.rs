@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CnctdStudioError, state::{band_pda::{BandMember, BandPDA}, treasury::{AdminRole, Treasury}, user_pda::UserPDA}};
+
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct AddBandMember<'info> {
+    /// Membership decides who is paid the band's revenue, so it takes a super admin
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Bands created before membership must be brought to the current layout with
+    /// migrate_account first, they don't deserialize until then
+    #[account(
+        mut,
+        constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount,
+    )]
+    pub band_pda: Account<'info, BandPDA>,
+
+    /// The member's user account
+    pub member: Account<'info, UserPDA>,
+}
+
+pub fn add_band_member(ctx: Context<AddBandMember>, _band_id: String, share_bps: u16) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let band = &mut ctx.accounts.band_pda;
+
+    require!(band.member_index(&member).is_none(), CnctdStudioError::BandMemberExists);
+
+    band.members.push(BandMember { user_pda: member, share_bps });
+    band.validate_members()?;
+
+    msg!("Added member {} to band {} with {} bps ({} bps allocated)",
+        member, band.key(), share_bps, band.total_share_bps());
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use anchor_spl::token_2022::Token2022;
 
 use crate::arguments::metadata::Metadata;
 use crate::errors::CnctdStudioError;
-use crate::state::treasury::Treasury;
+use crate::state::treasury::{AdminRole, Treasury};
 
 #[derive(Accounts)]
 pub struct AddMetaplexMetadata<'info> {
@@ -47,7 +47,7 @@ pub fn add_metaplex_metadata(
     ctx: Context<AddMetaplexMetadata>,
     metadata: Metadata,
 ) -> Result<()> {
-    if !ctx.accounts.treasury.is_admin(&ctx.accounts.payer.key()) {
+    if !ctx.accounts.treasury.has_role(&ctx.accounts.payer.key(), AdminRole::SuperAdmin) {
         return Err(CnctdStudioError::Unauthorized.into());
     }
 
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{governance::{Governance, Proposal}, treasury::Treasury},
+};
+
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// Admin holding the role the proposed action requires
+    #[account(
+        constraint = treasury.has_role(&approver.key(), proposal.action.required_role()) @ CnctdStudioError::Unauthorized
+    )]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ CnctdStudioError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ CnctdStudioError::ProposalCancelled,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    let proposal = &mut ctx.accounts.proposal;
+
+    require!(!proposal.approvals.contains(&approver), CnctdStudioError::AlreadyApproved);
+
+    proposal.approvals.push(approver);
+
+    msg!(
+        "Proposal {} approved by {} ({}/{} approvals)",
+        proposal.id,
+        approver,
+        proposal.valid_approvals(&ctx.accounts.treasury),
+        ctx.accounts.governance.approval_threshold
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CnctdStudioError, state::{governance::Governance, treasury::{AdminRole, Treasury}, user_pda::UserPDA}};
+
+/// Binds a user's own wallet as the `auth` key of their PDA, or unbinds it with `None`.
+/// The wallet can withdraw the user's funds, so this is held to the same governance as admin changes.
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct BindUserAuth<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        constraint = user_pda.has_address(&user_pda.key(), &user_id) @ CnctdStudioError::InvalidUserAccount,
+    )]
+    pub user_pda: Account<'info, UserPDA>,
+
+    /// Direct binding is only allowed without a multisig threshold or admin change delay
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = !governance.requires_proposal() @ CnctdStudioError::MultisigApprovalRequired,
+        constraint = !governance.requires_queued_admin_change() @ CnctdStudioError::AdminChangeQueued
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+pub fn bind_user_auth(ctx: Context<BindUserAuth>, _user_id: String, auth: Option<Pubkey>) -> Result<()> {
+    let user_key = ctx.accounts.user_pda.key();
+
+    apply_user_auth(&mut ctx.accounts.user_pda, user_key, auth);
+
+    Ok(())
+}
+
+/// Sets the wallet bound to the user at `user_key`. Shared with multisig proposal execution.
+pub fn apply_user_auth(user_pda: &mut UserPDA, user_key: Pubkey, auth: Option<Pubkey>) {
+    // Custody accounts belong to the previous wallet, so they don't carry over
+    if user_pda.auth != auth {
+        user_pda.usdc_cust = None;
+        user_pda.cnctd_cust = None;
+        user_pda.music_cust = None;
+    }
+    user_pda.auth = auth;
+
+    msg!("User {} auth set to {:?}", user_key, auth);
+}
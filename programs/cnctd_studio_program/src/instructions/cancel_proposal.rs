@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{governance::Proposal, treasury::Treasury},
+};
+
+/// Any admin can veto a pending proposal, e.g. a queued admin change made with a compromised key.
+/// The proposer is deliberately included so they can withdraw a mistaken proposal: cancelling
+/// only ever stops an action, so it gives no way around the approval threshold or the timelock.
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    #[account(
+        constraint = treasury.is_admin(&admin.key()) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ CnctdStudioError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ CnctdStudioError::ProposalCancelled,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.cancelled = true;
+
+    msg!("Proposal {} cancelled by {}", proposal.id, ctx.accounts.admin.key());
+
+    Ok(())
+}
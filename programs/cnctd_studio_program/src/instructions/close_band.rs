@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{close_account, CloseAccount, Mint, TokenAccount},
+};
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{band_pda::BandPDA, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}},
+};
+
+/// Dissolves a band. Its ATAs must already be empty, see `transfer_band_assets`, and no release
+/// may still pay it: move those releases' splits to other recipients first.
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct CloseBand<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The treasury that will receive the reclaimed rent
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        mut,
+        constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount,
+        constraint = band_pda.release_refs == 0 @ CnctdStudioError::BandStillReferenced,
+        close = treasury
+    )]
+    pub band_pda: Account<'info, BandPDA>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
+    pub cnctd_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&band_pda.key(), &usdc_mint.key(), &token_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner,
+        constraint = band_usdc_ata.amount == 0 @ CnctdStudioError::TokenAccountNotEmpty,
+    )]
+    pub band_usdc_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&band_pda.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner,
+        constraint = band_cnctd_ata.amount == 0 @ CnctdStudioError::TokenAccountNotEmpty,
+    )]
+    pub band_cnctd_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn close_band(ctx: Context<CloseBand>, band_id: String) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let band_seed = accounts.band_pda.signer_seed_id(&accounts.band_pda.key(), &band_id)?;
+    let band_seeds: &[&[u8]] = &[b"band", band_seed.as_ref(), &[accounts.band_pda.bump]];
+
+    for (ata, token_program) in [
+        (&accounts.band_usdc_ata, accounts.token_program.to_account_info()),
+        (&accounts.band_cnctd_ata, accounts.token_2022_program.to_account_info()),
+    ] {
+        close_account(CpiContext::new_with_signer(
+            token_program,
+            CloseAccount {
+                account: ata.to_account_info(),
+                destination: accounts.treasury.to_account_info(),
+                authority: accounts.band_pda.to_account_info(),
+            },
+            &[band_seeds],
+        ))?;
+
+        msg!("Closed token account {}", ata.key());
+    }
+
+    // The band account itself is closed by Anchor via the `close = treasury` constraint
+    msg!("Band {} closed and rent returned to treasury {}", accounts.band_pda.key(), accounts.treasury.key());
+
+    Ok(())
+}
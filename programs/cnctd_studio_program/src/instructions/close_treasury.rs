@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::errors::CnctdStudioError;
-use crate::state::treasury::Treasury;
+use crate::state::treasury::{AdminRole, Treasury};
 
 #[derive(Accounts)]
 pub struct CloseTreasury<'info> {
@@ -21,7 +21,7 @@ pub struct CloseTreasury<'info> {
 }
 
 pub fn close_treasury(ctx: Context<CloseTreasury>) -> Result<()> {
-    if !ctx.accounts.treasury.is_admin(&ctx.accounts.payer.key()) {
+    if !ctx.accounts.treasury.has_role(&ctx.accounts.payer.key(), AdminRole::SuperAdmin) {
         return Err(CnctdStudioError::Unauthorized.into());
     }
     
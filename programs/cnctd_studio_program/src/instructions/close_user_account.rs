@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TransferChecked},
+};
 
-use crate::state::{treasury::Treasury, user_pda::UserPDA};
+use crate::{errors::CnctdStudioError, state::{program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA}};
 
 #[derive(Accounts)]
 #[instruction(user_id: String)]
@@ -8,12 +14,11 @@ pub struct CloseUserAccount<'info> {
     /// The user PDA being closed
     #[account(
         mut,
-        seeds = [b"user", user_id.as_bytes()],
-        bump,
+        constraint = user_pda.has_address(&user_pda.key(), &user_id) @ CnctdStudioError::InvalidUserAccount,
         close = treasury // Send lamports back to treasury
     )]
     pub user_pda: Account<'info, UserPDA>,
-    
+
     /// The treasury that will receive the reclaimed rent
     #[account(
         mut,
@@ -21,19 +26,146 @@ pub struct CloseUserAccount<'info> {
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
-    
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Withdrawals) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
     /// Admin that is authorized to close the account
-    #[account(constraint = treasury.is_admin(&admin.key()))]
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::Operator))]
     pub admin: Signer<'info>,
-    
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
+    pub cnctd_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = program_metadata.music_mint @ CnctdStudioError::InvalidMint)]
+    pub music_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: The user's USDC ATA, skipped if it was never created
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &usdc_mint.key(), &token_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub usdc_ata: UncheckedAccount<'info>,
+
+    /// CHECK: The user's CNCTD ATA, skipped if it was never created
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub cnctd_ata: UncheckedAccount<'info>,
+
+    /// CHECK: The user's MUSIC ATA, skipped if it was never created
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &music_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub music_ata: UncheckedAccount<'info>,
+
+    /// Receives any USDC left in the user's ATA: their custody account or their wallet's ATA
+    #[account(
+        mut,
+        constraint = usdc_destination.mint == usdc_mint.key() @ CnctdStudioError::InvalidMint,
+        constraint = user_pda.is_sweep_destination(&usdc_mint.key(), &token_program.key(), &program_metadata, &usdc_destination.key()) @ CnctdStudioError::CustodyDestinationRequired,
+    )]
+    pub usdc_destination: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives any CNCTD left in the user's ATA: their custody account or their wallet's ATA
+    #[account(
+        mut,
+        constraint = cnctd_destination.mint == cnctd_mint.key() @ CnctdStudioError::InvalidMint,
+        constraint = user_pda.is_sweep_destination(&cnctd_mint.key(), &token_2022_program.key(), &program_metadata, &cnctd_destination.key()) @ CnctdStudioError::CustodyDestinationRequired,
+    )]
+    pub cnctd_destination: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Receives any MUSIC left in the user's ATA: their custody account or their wallet's ATA
+    #[account(
+        mut,
+        constraint = music_destination.mint == music_mint.key() @ CnctdStudioError::InvalidMint,
+        constraint = user_pda.is_sweep_destination(&music_mint.key(), &token_2022_program.key(), &program_metadata, &music_destination.key()) @ CnctdStudioError::CustodyDestinationRequired,
+    )]
+    pub music_destination: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn close_user_account(ctx: Context<CloseUserAccount>, user_id: String) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let user_seed = accounts.user_pda.signer_seed_id(&accounts.user_pda.key(), &user_id)?;
+    let user_seeds: &[&[u8]] = &[b"user", user_seed.as_ref(), &[accounts.user_pda.bump]];
+
+    // Sweep each ATA to its destination and return its rent to the treasury
+    sweep_and_close(accounts, &accounts.usdc_ata, &accounts.usdc_mint, accounts.usdc_destination.as_ref(), accounts.token_program.to_account_info(), user_seeds)?;
+    sweep_and_close(accounts, &accounts.cnctd_ata, &accounts.cnctd_mint, accounts.cnctd_destination.as_ref(), accounts.token_2022_program.to_account_info(), user_seeds)?;
+    sweep_and_close(accounts, &accounts.music_ata, &accounts.music_mint, accounts.music_destination.as_ref(), accounts.token_2022_program.to_account_info(), user_seeds)?;
+
     // The account closing is handled automatically by Anchor via the `close = treasury` constraint
     msg!("User account {} closed and rent returned to treasury {}",
-         ctx.accounts.user_pda.key(),
-         ctx.accounts.treasury.key());
-    
+         accounts.user_pda.key(),
+         accounts.treasury.key());
+
+    Ok(())
+}
+
+fn sweep_and_close<'info>(
+    accounts: &CloseUserAccount<'info>,
+    ata: &UncheckedAccount<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: Option<&InterfaceAccount<'info, TokenAccount>>,
+    token_program: AccountInfo<'info>,
+    user_seeds: &[&[u8]],
+) -> Result<()> {
+    if ata.data_is_empty() {
+        msg!("Token account {} was never created, skipping", ata.key());
+        return Ok(());
+    }
+
+    let amount = TokenAccount::try_deserialize(&mut &ata.data.borrow()[..])?.amount;
+    if amount > 0 {
+        let destination = destination.ok_or(error!(CnctdStudioError::TokenAccountNotEmpty))?;
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TransferChecked {
+                    from: ata.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: accounts.user_pda.to_account_info(),
+                },
+                &[user_seeds],
+            ),
+            amount,
+            mint.decimals,
+        )?;
+
+        msg!("Swept {} of mint {} to {}", amount, mint.key(), destination.key());
+    }
+
+    close_account(CpiContext::new_with_signer(
+        token_program,
+        CloseAccount {
+            account: ata.to_account_info(),
+            destination: accounts.treasury.to_account_info(),
+            authority: accounts.user_pda.to_account_info(),
+        },
+        &[user_seeds],
+    ))?;
+
+    msg!("Closed token account {}", ata.key());
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{governance::{Governance, Proposal, ProposalAction}, treasury::Treasury},
+};
+
+#[derive(Accounts)]
+#[instruction(action: ProposalAction)]
+pub struct CreateProposal<'info> {
+    /// Admin holding the role the action requires
+    #[account(
+        mut,
+        constraint = treasury.has_role(&proposer.key(), action.required_role()) @ CnctdStudioError::Unauthorized
+    )]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::space(),
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    match action {
+        ProposalAction::SetThreshold { threshold } => Governance::validate_threshold(threshold, &ctx.accounts.treasury)?,
+        ProposalAction::SetAdminChangeDelay { delay } => Governance::validate_admin_change_delay(delay)?,
+        ProposalAction::SetAdminRoles { target, .. } => {
+            require!(ctx.accounts.treasury.is_admin(&target), CnctdStudioError::AdminNotFound)
+        }
+        _ => {}
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let governance = &mut ctx.accounts.governance;
+    let proposal = &mut ctx.accounts.proposal;
+
+    proposal.id = governance.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.eta = match action.is_timelocked() {
+        true => now.checked_add(governance.admin_change_delay).ok_or(CnctdStudioError::MathOverflow)?,
+        false => now,
+    };
+    proposal.action = action;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.created_at = now;
+    proposal.executed = false;
+    proposal.cancelled = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance.proposal_count
+        .checked_add(1)
+        .ok_or(CnctdStudioError::MathOverflow)?;
+
+    msg!("Proposal {} created by {}: {:?}, executable from {}", proposal.id, proposal.proposer, proposal.action, proposal.eta);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token::{transfer, Mint, Token, TokenAccount, Transfer}};
+
+use crate::{
+    constants::BASIS_POINTS,
+    errors::CnctdStudioError,
+    math::apportion,
+    state::{band_pda::BandPDA, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}},
+};
+
+/// Splits the band's USDC balance between its members by share.
+/// Remaining accounts: each member's UserPDA USDC ATA, in the order of `band_pda.members`.
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct DistributeBandRevenue<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Withdrawals) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount,
+        constraint = !band_pda.members.is_empty() @ CnctdStudioError::BandMemberNotFound,
+        constraint = band_pda.total_share_bps() == BASIS_POINTS @ CnctdStudioError::InvalidSplitTotal,
+    )]
+    pub band_pda: Account<'info, BandPDA>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address(&band_pda.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub band_usdc_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn distribute_band_revenue<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeBandRevenue<'info>>,
+    band_id: String,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let band = &accounts.band_pda;
+    let balance = accounts.band_usdc_ata.amount;
+
+    msg!("Distributing {} USDC from band {} to {} members", balance, band.key(), band.members.len());
+
+    if balance == 0 {
+        msg!("Band USDC balance is zero, nothing to distribute");
+        return Ok(());
+    }
+
+    require!(
+        ctx.remaining_accounts.len() >= band.members.len(),
+        CnctdStudioError::NotEnoughAccounts
+    );
+
+    let weights: Vec<u64> = band.members.iter().map(|member| member.share_bps as u64).collect();
+    let amounts = apportion(balance, &weights)?;
+
+    let band_seed = band.signer_seed_id(&band.key(), &band_id)?;
+    let band_seeds: &[&[u8]] = &[b"band", band_seed.as_ref(), &[band.bump]];
+
+    for ((member, amount), recipient_ata) in band.members.iter().zip(amounts).zip(ctx.remaining_accounts) {
+        require_keys_eq!(
+            recipient_ata.key(),
+            get_associated_token_address(&member.user_pda, &accounts.usdc_mint.key()),
+            CnctdStudioError::InvalidPaymentReceiver
+        );
+
+        if amount == 0 {
+            msg!("Share for member {} is zero, skipping", member.user_pda);
+            continue;
+        }
+
+        transfer(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                Transfer {
+                    from: accounts.band_usdc_ata.to_account_info(),
+                    to: recipient_ata.clone(),
+                    authority: band.to_account_info(),
+                },
+                &[band_seeds],
+            ),
+            amount,
+        )?;
+
+        msg!("Paid {} USDC to member {}", amount, member.user_pda);
+    }
+
+    msg!("Band revenue distributed");
+    Ok(())
+}
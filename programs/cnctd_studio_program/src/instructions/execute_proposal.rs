@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token::Token, token_2022::Token2022, token_interface::{Mint, TokenAccount}};
+
+use crate::{
+    errors::CnctdStudioError,
+    instructions::{bind_user_auth::apply_user_auth, mint_tokens::mint_from_treasury, set_admin_roles::apply_admin_roles, transfer_tokens::transfer_from_treasury, update_admins::apply_admin_action},
+    state::{emission_budget::EmissionBudget, governance::{Governance, Proposal, ProposalAction}, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::{CanonicalMint, ProgramMetadata}, treasury::Treasury, user_pda::UserPDA},
+};
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// Any admin can execute once the threshold is met
+    #[account(
+        mut,
+        constraint = treasury.is_admin(&executor.key()) @ CnctdStudioError::Unauthorized
+    )]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        realloc = Treasury::space(),
+        realloc::payer = executor,
+        realloc::zero = false,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"governance"],
+        bump = governance.bump,
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ CnctdStudioError::ProposalAlreadyExecuted,
+        constraint = !proposal.cancelled @ CnctdStudioError::ProposalCancelled,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    /// CHECK: Token mint for transfer, mint and canonical mint proposals, checked against the proposal
+    #[account(mut)]
+    pub mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Treasury ATA for transfer proposals, address derived in the handler
+    #[account(mut)]
+    pub treasury_ata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient token account for transfer, mint and fee recipient proposals, checked against the proposal
+    #[account(mut)]
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// Supply governance for mint proposals
+    #[account(mut)]
+    pub emission_budget: Option<Account<'info, EmissionBudget>>,
+
+    /// User whose wallet is bound by user auth proposals
+    #[account(mut)]
+    pub user_pda: Option<Account<'info, UserPDA>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let approvals = ctx.accounts.proposal.valid_approvals(&ctx.accounts.treasury);
+    let threshold = ctx.accounts.governance.approval_threshold;
+
+    msg!("Executing proposal {} ({}/{} approvals)", ctx.accounts.proposal.id, approvals, threshold);
+
+    require!(approvals >= threshold as usize, CnctdStudioError::InsufficientApprovals);
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.proposal.eta,
+        CnctdStudioError::TimelockNotElapsed
+    );
+
+    if matches!(ctx.accounts.proposal.action, ProposalAction::TransferTokens { .. } | ProposalAction::MintTokens { .. }) {
+        require!(
+            !ctx.accounts.program_config.is_paused(PauseSubsystem::AdminOps),
+            CnctdStudioError::ProgramPaused
+        );
+    }
+
+    match ctx.accounts.proposal.action.clone() {
+        ProposalAction::UpdateAdmin { action, target } => {
+            apply_admin_action(&mut ctx.accounts.treasury, &action, target)?;
+
+            // Lower the threshold first if removing an admin would make it unreachable
+            Governance::validate_threshold(threshold, &ctx.accounts.treasury)?;
+        }
+        ProposalAction::SetAdminRoles { target, roles } => {
+            apply_admin_roles(&mut ctx.accounts.treasury, target, roles)?;
+        }
+        ProposalAction::SetCanonicalMint { kind, mint } => {
+            let mint_info = ctx.accounts.mint.as_ref().ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+            require_keys_eq!(mint_info.key(), mint, CnctdStudioError::ProposalAccountMismatch);
+
+            // USDC is a standard SPL mint, the reward mints are Token-2022 and minted by the treasury
+            let standard = kind == CanonicalMint::Usdc;
+            require_keys_eq!(*mint_info.owner, ctx.accounts.token_program_for(standard).key(), CnctdStudioError::InvalidMint);
+            let mint_state = Mint::try_deserialize(&mut &mint_info.data.borrow()[..])?;
+            if !standard {
+                require!(
+                    mint_state.mint_authority == COption::Some(ctx.accounts.treasury.key()),
+                    CnctdStudioError::InvalidMintAuthority
+                );
+            }
+
+            let program_metadata = &mut ctx.accounts.program_metadata;
+            program_metadata.set_mint(kind, mint);
+            program_metadata.updated_at = Clock::get()?.unix_timestamp;
+
+            msg!("Canonical {:?} mint set to {}", kind, mint);
+        }
+        ProposalAction::SetFeeRecipient { fee_recipient } => {
+            let destination = ctx.accounts.destination.as_ref().ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+            require_keys_eq!(destination.key(), fee_recipient, CnctdStudioError::ProposalAccountMismatch);
+            require_keys_eq!(*destination.owner, ctx.accounts.token_program.key(), CnctdStudioError::InvalidTokenAccount);
+
+            let token_account = TokenAccount::try_deserialize(&mut &destination.data.borrow()[..])?;
+            require_keys_eq!(token_account.mint, ctx.accounts.program_metadata.usdc_mint, CnctdStudioError::InvalidMint);
+
+            let program_metadata = &mut ctx.accounts.program_metadata;
+            program_metadata.fee_recipient = fee_recipient;
+            program_metadata.updated_at = Clock::get()?.unix_timestamp;
+
+            msg!("Treasury fee recipient set to {}", fee_recipient);
+        }
+        ProposalAction::BindUserAuth { user_pda, auth } => {
+            let user_account = ctx.accounts.user_pda
+                .as_mut()
+                .ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+            require_keys_eq!(user_account.key(), user_pda, CnctdStudioError::ProposalAccountMismatch);
+
+            apply_user_auth(user_account, user_pda, auth);
+        }
+        ProposalAction::SetThreshold { threshold } => {
+            Governance::validate_threshold(threshold, &ctx.accounts.treasury)?;
+
+            let governance = &mut ctx.accounts.governance;
+            governance.approval_threshold = threshold;
+            governance.updated_at = Clock::get()?.unix_timestamp;
+
+            msg!("Approval threshold set to {}", threshold);
+        }
+        ProposalAction::SetAdminChangeDelay { delay } => {
+            Governance::validate_admin_change_delay(delay)?;
+
+            let governance = &mut ctx.accounts.governance;
+            governance.admin_change_delay = delay;
+            governance.updated_at = Clock::get()?.unix_timestamp;
+
+            msg!("Admin change delay set to {}s", delay);
+        }
+        ProposalAction::TransferTokens { mint, recipient_ata, amount, standard } => {
+            let (mint_info, source, destination) = proposal_token_accounts(ctx.accounts, mint, recipient_ata)?;
+            let source = source.ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+            let token_program = ctx.accounts.token_program_for(standard);
+            require_keys_eq!(
+                source.key(),
+                get_associated_token_address_with_program_id(&ctx.accounts.treasury.key(), &mint, &token_program.key()),
+                CnctdStudioError::InvalidTreasuryATA
+            );
+
+            transfer_from_treasury(
+                token_program,
+                standard,
+                source,
+                mint_info,
+                destination,
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.treasury.bump,
+                amount,
+            )?;
+        }
+        ProposalAction::MintTokens { mint, destination, amount, standard } => {
+            let (mint_info, _, destination) = proposal_token_accounts(ctx.accounts, mint, destination)?;
+
+            let emission_budget = ctx.accounts.emission_budget
+                .as_mut()
+                .ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+            require_keys_eq!(emission_budget.mint, mint, CnctdStudioError::ProposalAccountMismatch);
+
+            let amount = emission_budget.consume(amount, Clock::get()?.unix_timestamp)?;
+
+            mint_from_treasury(
+                ctx.accounts.token_program_for(standard),
+                standard,
+                mint_info,
+                destination,
+                ctx.accounts.treasury.to_account_info(),
+                ctx.accounts.treasury.bump,
+                amount,
+            )?;
+        }
+    }
+
+    ctx.accounts.proposal.executed = true;
+
+    msg!("Proposal {} executed by {}", ctx.accounts.proposal.id, ctx.accounts.executor.key());
+    Ok(())
+}
+
+impl<'info> ExecuteProposal<'info> {
+    fn token_program_for(&self, standard: bool) -> AccountInfo<'info> {
+        match standard {
+            true => self.token_program.to_account_info(),
+            false => self.token_2022_program.to_account_info(),
+        }
+    }
+}
+
+/// Resolves the optional token accounts and checks them against the proposed mint and destination
+fn proposal_token_accounts<'info>(
+    accounts: &ExecuteProposal<'info>,
+    mint: Pubkey,
+    destination: Pubkey,
+) -> Result<(AccountInfo<'info>, Option<AccountInfo<'info>>, AccountInfo<'info>)> {
+    let mint_info = accounts.mint.as_ref().ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+    let destination_info = accounts.destination.as_ref().ok_or(CnctdStudioError::ProposalAccountMismatch)?;
+
+    require_keys_eq!(mint_info.key(), mint, CnctdStudioError::ProposalAccountMismatch);
+    require!(accounts.program_metadata.is_canonical_mint(&mint), CnctdStudioError::InvalidMint);
+    require_keys_eq!(destination_info.key(), destination, CnctdStudioError::ProposalAccountMismatch);
+
+    Ok((
+        mint_info.to_account_info(),
+        accounts.treasury_ata.as_ref().map(|source| source.to_account_info()),
+        destination_info.to_account_info(),
+    ))
+}
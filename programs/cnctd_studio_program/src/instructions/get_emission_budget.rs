@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::emission_budget::EmissionBudget;
+
+#[derive(Accounts)]
+pub struct GetEmissionBudget<'info> {
+    #[account(
+        seeds = [b"emission_budget", emission_budget.mint.as_ref()],
+        bump = emission_budget.bump
+    )]
+    pub emission_budget: Account<'info, EmissionBudget>,
+}
+
+pub fn get_emission_budget(ctx: Context<GetEmissionBudget>) -> Result<()> {
+    let mut emission_budget = (*ctx.accounts.emission_budget).clone();
+    emission_budget.roll_epoch(Clock::get()?.unix_timestamp);
+
+    msg!("📜 Emission Budget Data:");
+    msg!("Mint: {}", emission_budget.mint);
+    msg!("Epoch duration: {}s, epoch start: {}", emission_budget.epoch_duration, emission_budget.epoch_start);
+    msg!("Epoch minted: {} / {}", emission_budget.epoch_minted, emission_budget.epoch_budget);
+    msg!("Lifetime minted: {} / {}", emission_budget.lifetime_minted, emission_budget.lifetime_cap);
+    msg!("Remaining: {}", emission_budget.remaining());
+    msg!("Scale down: {}", emission_budget.scale_down);
+
+    Ok(())
+}
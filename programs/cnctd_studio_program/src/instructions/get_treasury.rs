@@ -12,6 +12,7 @@ pub fn get_treasury(ctx: Context<GetTreasury>) -> Result<()> {
 
     msg!("📜 Treasury Data:");
     msg!("Admins: {:?}", treasury.admins);
+    msg!("Roles: {:?}", treasury.roles);
     msg!("Bump: {}", treasury.bump);
     msg!("Version: {}", treasury.version);
 
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::Token, token_2022::Token2022};
-use crate::{arguments::initialize_band_args::InitializeBandArgs, state::{treasury::Treasury, band_pda::BandPDA}};
+use anchor_spl::{associated_token::{get_associated_token_address_with_program_id, AssociatedToken}, token::Token, token_2022::Token2022};
+use crate::{errors::CnctdStudioError, pda, arguments::initialize_band_args::InitializeBandArgs, state::{reimbursement::{AdminUsage, ReimbursementConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, band_pda::BandPDA, versioning::Versioned}, utils::UuidFormatting};
 
 #[derive(Accounts)]
 #[instruction(args: InitializeBandArgs)]
@@ -8,11 +8,19 @@ pub struct InitializeBand<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + std::mem::size_of::<BandPDA>(),
-        seeds = [b"band", args.band_id.as_bytes()],
+        space = BandPDA::space(),
+        seeds = [b"band", args.band_id.to_uuid_bytes().as_ref()],
         bump
     )]
     pub band_pda: Account<'info, BandPDA>,
+
+    /// CHECK: Where this band would live under the seeds of older bands. Must be unused, so an
+    /// id can't be onboarded twice.
+    #[account(
+        constraint = pda::legacy_band(&args.band_id).is_some_and(|(key, _)| key == legacy_band_pda.key()) @ CnctdStudioError::InvalidBandAccount,
+        constraint = legacy_band_pda.data_is_empty() @ CnctdStudioError::LegacyBandExists
+    )]
+    pub legacy_band_pda: UncheckedAccount<'info>,
     
     #[account(
         mut,
@@ -20,13 +28,35 @@ pub struct InitializeBand<'info> {
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
     
     #[account(
         mut,
-        constraint = treasury.is_admin(&admin.key())
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator)
     )]
     pub admin: Signer<'info>,
     
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: Account<'info, anchor_spl::token::Mint>,
     
     #[account(
@@ -37,11 +67,15 @@ pub struct InitializeBand<'info> {
     )]
     pub usdc_ata: Account<'info, anchor_spl::token::TokenAccount>,
     
-    /// CHECK: This is a Token-2022 mint account
+    /// CHECK: This is a Token-2022 mint account, checked against program metadata
+    #[account(address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
     pub cnctd_mint: UncheckedAccount<'info>,
     
     /// CHECK: This is a Token-2022 token account
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&band_pda.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub cnctd_ata: UncheckedAccount<'info>,
     
     pub token_program: Program<'info, Token>,
@@ -53,6 +87,8 @@ pub struct InitializeBand<'info> {
 pub fn initialize_band(ctx: Context<InitializeBand>, args: InitializeBandArgs) -> Result<()> {
     msg!("Initializing band: {}", args.band_id);
 
+    require!(args.band_id.is_valid_uuid(), CnctdStudioError::InvalidInput);
+
     // Create CNCTD ATA manually since we're using UncheckedAccount
     if ctx.accounts.cnctd_ata.data_is_empty() {
         msg!("Creating CNCTD ATA for band");
@@ -82,14 +118,19 @@ pub fn initialize_band(ctx: Context<InitializeBand>, args: InitializeBandArgs) -
     band.fees_waived = 0;
     band.waived_count = 0;
     band.bump = ctx.bumps.band_pda;
+    band.version = BandPDA::VERSION;
+    band.members = Vec::new();
+    band.release_refs = 0;
     
     // Reimburse the admin for any fees incurred
-    let space = 8 + std::mem::size_of::<BandPDA>();
+    let space = BandPDA::space();
     let rent_lamports = Rent::get()?.minimum_balance(space);
 
     ctx.accounts.treasury.reimburse_admin(
         &ctx.accounts.treasury.to_account_info(),
         &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
         args.fee_compensation,
         Some(rent_lamports),
     )?;
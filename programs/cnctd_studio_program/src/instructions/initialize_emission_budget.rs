@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::emission_budget_args::EmissionBudgetArgs,
+    errors::CnctdStudioError,
+    state::{emission_budget::EmissionBudget, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct InitializeEmissionBudget<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Reward mint governed by the budget, only used as a seed
+    pub mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = EmissionBudget::space(),
+        seeds = [b"emission_budget", mint.key().as_ref()],
+        bump
+    )]
+    pub emission_budget: Account<'info, EmissionBudget>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_emission_budget(ctx: Context<InitializeEmissionBudget>, args: EmissionBudgetArgs) -> Result<()> {
+    require!(args.epoch_duration > 0, CnctdStudioError::InvalidInput);
+
+    let now = Clock::get()?.unix_timestamp;
+    let emission_budget = &mut ctx.accounts.emission_budget;
+
+    emission_budget.mint = ctx.accounts.mint.key();
+    emission_budget.epoch_duration = args.epoch_duration;
+    emission_budget.epoch_budget = args.epoch_budget;
+    emission_budget.lifetime_cap = args.lifetime_cap;
+    emission_budget.scale_down = args.scale_down;
+    emission_budget.epoch_start = now;
+    emission_budget.epoch_minted = 0;
+    emission_budget.lifetime_minted = 0;
+    emission_budget.updated_at = now;
+    emission_budget.bump = ctx.bumps.emission_budget;
+
+    msg!("Emission budget initialized for mint {}: {:?}", emission_budget.mint, args);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{governance::Governance, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Governance::space(),
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_governance(ctx: Context<InitializeGovernance>, approval_threshold: u8, admin_change_delay: i64) -> Result<()> {
+    Governance::validate_threshold(approval_threshold, &ctx.accounts.treasury)?;
+    Governance::validate_admin_change_delay(admin_change_delay)?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.approval_threshold = approval_threshold;
+    governance.proposal_count = 0;
+    governance.updated_at = Clock::get()?.unix_timestamp;
+    governance.bump = ctx.bumps.governance;
+    governance.admin_change_delay = admin_change_delay;
+
+    msg!("Governance initialized with approval threshold {}, admin change delay {}s", approval_threshold, admin_change_delay);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{program_config::ProgramConfig, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProgramConfig::space(),
+        seeds = [b"program_config"],
+        bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>, guardian: Pubkey) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+
+    program_config.guardian = guardian;
+    program_config.paused = 0;
+    program_config.updated_at = Clock::get()?.unix_timestamp;
+    program_config.bump = ctx.bumps.program_config;
+
+    msg!("Program config initialized with guardian {}", guardian);
+
+    Ok(())
+}
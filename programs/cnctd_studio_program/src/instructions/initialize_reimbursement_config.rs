@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::reimbursement_config_args::ReimbursementConfigArgs,
+    errors::CnctdStudioError,
+    state::{reimbursement::ReimbursementConfig, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct InitializeReimbursementConfig<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ReimbursementConfig::space(),
+        seeds = [b"reimbursement_config"],
+        bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_reimbursement_config(ctx: Context<InitializeReimbursementConfig>, args: ReimbursementConfigArgs) -> Result<()> {
+    let reimbursement_config = &mut ctx.accounts.reimbursement_config;
+
+    reimbursement_config.max_per_tx = args.max_per_tx;
+    reimbursement_config.max_per_day = args.max_per_day;
+    reimbursement_config.updated_at = Clock::get()?.unix_timestamp;
+    reimbursement_config.bump = ctx.bumps.reimbursement_config;
+
+    msg!("Reimbursement config initialized: {:?}", args);
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ use anchor_spl::associated_token::{self, AssociatedToken};
 
 use crate::arguments::initialize_token_mint_args::InitializeTokenMintArgs;
 use crate::errors::CnctdStudioError;
-use crate::state::treasury::Treasury;
+use crate::state::treasury::{AdminRole, Treasury};
 
 #[derive(Accounts)]
 pub struct InitializeTokenMint<'info> {
@@ -53,7 +53,7 @@ pub fn initialize_token_mint(ctx: Context<InitializeTokenMint>, data: Initialize
     let treasury_ata = &ctx.accounts.treasury_ata; 
     let decimals = 6;
 
-    if !treasury_pda.is_admin(&signer.key()) {
+    if !treasury_pda.has_role(&signer.key(), AdminRole::SuperAdmin) {
         return Err(CnctdStudioError::Unauthorized.into());
     }
 
@@ -7,7 +7,7 @@ pub struct InitializeTreasury<'info> {
     #[account(
         init,
         payer = admin,
-        space = 8 + (32 * 5) + 1, // Supports up to 5 admin pubkeys
+        space = Treasury::space(), // Supports up to MAX_ADMINS admins and their roles
         seeds = [b"treasury"],
         bump
     )]
@@ -29,6 +29,7 @@ pub fn handler(ctx: Context<InitializeTreasury>, mut initial_admins: Vec<Pubkey>
     }
 
     treasury.admins = initial_admins;
+    treasury.roles = vec![]; // Every admin has full access until roles are granted
     treasury.bump = ctx.bumps.treasury;
     treasury.version = 1;
 
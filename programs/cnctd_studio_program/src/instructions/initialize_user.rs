@@ -1,13 +1,24 @@
-use anchor_lang::{prelude::*, solana_program::{program::invoke_signed, system_instruction}};
-use anchor_spl::{associated_token::{create, AssociatedToken}, token::Token, token_2022::Token2022};
-use crate::{arguments::initialize_user_args::InitializeUserArgs, state::{treasury::Treasury, user_pda::UserPDA}};
+use anchor_lang::{prelude::*, solana_program::{program::{invoke, invoke_signed}, system_instruction}};
+use anchor_spl::{associated_token::{create, get_associated_token_address, get_associated_token_address_with_program_id, AssociatedToken}, token::Token, token_2022::Token2022};
+use crate::{errors::CnctdStudioError, pda, arguments::initialize_user_args::InitializeUserArgs, state::{reimbursement::{AdminUsage, ReimbursementConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA, versioning::Versioned}, utils::UuidFormatting};
 
 #[derive(Accounts)]
 #[instruction(args: InitializeUserArgs)]
 pub struct InitializeUser<'info> {
-    /// CHECK: The user PDA being initialized
-    #[account(mut)]
+    /// CHECK: The user PDA being initialized, or an existing one whose ATAs are being backfilled
+    #[account(
+        mut,
+        constraint = user_pda.key() == pda::user(&args.user_id).0
+            || user_pda.key() == legacy_user_pda.key() @ CnctdStudioError::InvalidUserAccount
+    )]
     pub user_pda: UncheckedAccount<'info>,
+
+    /// CHECK: Where this user would live under the seeds of older users. A new user is only
+    /// created while this is unused, so an id can't be onboarded twice.
+    #[account(
+        constraint = pda::legacy_user(&args.user_id).is_some_and(|(key, _)| key == legacy_user_pda.key()) @ CnctdStudioError::InvalidUserAccount
+    )]
+    pub legacy_user_pda: UncheckedAccount<'info>,
     
     // Treasury - source of funds
     #[account(
@@ -16,39 +27,75 @@ pub struct InitializeUser<'info> {
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
     
     // Admin - temporary intermediary payer
     #[account(
         mut, // Must be mutable since it receives and sends SOL
-        constraint = treasury.is_admin(&admin.key())
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator)
     )]
     pub admin: Signer<'info>,
     
     /// CHECK: Trasury USDC ATA
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&treasury.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTreasuryATA
+    )]
     pub treasury_usdc_ata: UncheckedAccount<'info>,
     
-    /// CHECK: USDC token mint
+    /// CHECK: USDC token mint, checked against program metadata
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: UncheckedAccount<'info>,
     
     /// CHECK: USDC ATA for user
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&user_pda.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub usdc_ata: UncheckedAccount<'info>,
     
     // CNCTD accounts (Token-2022 program)
-    /// CHECK: CNCTD token mint
+    /// CHECK: CNCTD token mint, checked against program metadata
+    #[account(address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
     pub cnctd_mint: UncheckedAccount<'info>,
     
     /// CHECK: CNCTD ATA for user
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub cnctd_ata: UncheckedAccount<'info>,
     
     // MUSIC accounts (Token-2022 program)
-    /// CHECK: MUSIC token mint
+    /// CHECK: MUSIC token mint, checked against program metadata
+    #[account(address = program_metadata.music_mint @ CnctdStudioError::InvalidMint)]
     pub music_mint: UncheckedAccount<'info>,
     
     /// CHECK: MUSIC ATA for user
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &music_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub music_ata: UncheckedAccount<'info>,
     
     // Programs
@@ -64,28 +111,72 @@ pub fn initialize_user(ctx: Context<InitializeUser>, args: InitializeUserArgs) -
     msg!("Treasury key: {}", ctx.accounts.treasury.key());
     msg!("User PDA key: {}", ctx.accounts.user_pda.key());
     msg!("Admin key: {}", ctx.accounts.admin.key());
-    
-    
-    
-    // Create the user PDA account and initialize it
-    create_user_pda(&ctx, &args)?;
-    
+
+    require!(args.user_id.is_valid_uuid(), CnctdStudioError::InvalidInput);
+
+    // Onboarding is safe to retry: an existing user only gets its missing ATAs backfilled
+    let created = if is_initialized_user(&ctx.accounts.user_pda)? {
+        msg!("UserPDA already initialized, checking token accounts");
+        false
+    } else {
+        // Only the current seed scheme is used for new users
+        require_keys_eq!(ctx.accounts.user_pda.key(), pda::user(&args.user_id).0, CnctdStudioError::InvalidUserAccount);
+        require!(ctx.accounts.legacy_user_pda.data_is_empty(), CnctdStudioError::LegacyUserExists);
+        create_user_pda(&ctx, &args)?;
+        true
+    };
+
+    create_missing_atas(&ctx)?;
+
+    // Deposits and rent reimbursement only happen on the call that created the user
+    let rent_lamports = match created {
+        true => {
+            deposit_usdc(&ctx, &args)?;
+            Some(Rent::get()?.minimum_balance(UserPDA::space()))
+        }
+        false => None,
+    };
+
+    // Now transfer SOL from treasury to admin to reimburse them
+    ctx.accounts.treasury.reimburse_admin(
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
+        args.fee_compensation,
+        rent_lamports,
+    )?;
+
+    msg!("Final admin balance: {}", ctx.accounts.admin.lamports());
+    msg!("Final treasury balance: {}", ctx.accounts.treasury.to_account_info().lamports());
+
     msg!("UserPDA initialized successfully");
     Ok(())
 }
 
+/// Whether the user PDA already holds a `UserPDA`. Anything else that isn't an empty
+/// system account can't be initialized over.
+fn is_initialized_user(user_pda: &UncheckedAccount) -> Result<bool> {
+    if user_pda.owner == &crate::ID {
+        UserPDA::try_deserialize(&mut &user_pda.data.borrow()[..])?;
+        return Ok(true);
+    }
+
+    require!(
+        user_pda.owner == &System::id() && user_pda.data_is_empty(),
+        CnctdStudioError::InvalidUserAccount
+    );
+    Ok(false)
+}
+
 fn create_user_pda(ctx: &Context<InitializeUser>, args: &InitializeUserArgs) -> Result<()> {
-    let user_id = &args.user_id;
-    let fee_compensation = args.fee_compensation.unwrap_or(0);
+    let user_seed = args.user_id.to_uuid_bytes();
     
     // Calculate user PDA and bump
-    let (_, user_bump) = Pubkey::find_program_address(
-        &[b"user", user_id.as_bytes()],
-        &crate::ID
-    );
+    let (_, user_bump) = pda::user(&args.user_id);
     
     // Calculate the required lamports for rent exemption
-    let space = 8 + std::mem::size_of::<UserPDA>();
+    let space = UserPDA::space();
     let rent = Rent::get()?;
     let rent_lamports = rent.minimum_balance(space);
     
@@ -95,28 +186,77 @@ fn create_user_pda(ctx: &Context<InitializeUser>, args: &InitializeUserArgs) ->
     msg!("Treasury balance before: {}", ctx.accounts.treasury.to_account_info().lamports());
     
     // Create user PDA using invoke_signed
-    let user_seeds = &[b"user" as &[u8], user_id.as_bytes(), &[user_bump]];
+    let user_seeds = &[b"user" as &[u8], user_seed.as_ref(), &[user_bump]];
     let user_signer_seeds = &[&user_seeds[..]];
+
+    let admin_info = ctx.accounts.admin.to_account_info();
+    let user_info = ctx.accounts.user_pda.to_account_info();
+    let system_info = ctx.accounts.system_program.to_account_info();
+    let current_lamports = user_info.lamports();
+
+    if current_lamports == 0 {
+        // Create the account directly from the admin
+        invoke_signed(
+            &system_instruction::create_account(
+                &admin_info.key(),  // Admin is the payer 
+                &user_info.key(),
+                rent_lamports,
+                space as u64,
+                &crate::ID,
+            ),
+            &[admin_info, user_info, system_info],
+            user_signer_seeds,
+        )?;
+    } else {
+        // Someone already sent lamports to the address, so create_account would fail.
+        // Top it up to rent exemption, then allocate and assign it instead.
+        let top_up = rent_lamports.saturating_sub(current_lamports);
+        if top_up > 0 {
+            invoke(
+                &system_instruction::transfer(&admin_info.key(), &user_info.key(), top_up),
+                &[admin_info.clone(), user_info.clone(), system_info.clone()],
+            )?;
+        }
+        invoke_signed(
+            &system_instruction::allocate(&user_info.key(), space as u64),
+            &[user_info.clone(), system_info.clone()],
+            user_signer_seeds,
+        )?;
+        invoke_signed(
+            &system_instruction::assign(&user_info.key(), &crate::ID),
+            &[user_info, system_info],
+            user_signer_seeds,
+        )?;
+    }
     
-    // Create the account directly from the admin
-    invoke_signed(
-        &system_instruction::create_account(
-            &ctx.accounts.admin.key(),  // Admin is the payer 
-            &ctx.accounts.user_pda.key(),
-            rent_lamports,
-            space as u64,
-            &crate::ID,
-        ),
-        &[
-            ctx.accounts.admin.to_account_info(),
-            ctx.accounts.user_pda.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-        ],
-        user_signer_seeds,
+    msg!("User PDA account created");
+
+    // Initialize the UserPDA fields
+    let mut user_pda: UserPDA = UserPDA::try_deserialize_unchecked(
+        &mut &ctx.accounts.user_pda.data.borrow()[..]
     )?;
     
-    msg!("User PDA account created");
+    // Set the fields with actual ATA pubkeys
+    user_pda.admin = ctx.accounts.treasury.key();
+    user_pda.auth = None;
+    user_pda.usdc_ata = ctx.accounts.usdc_ata.key();
+    user_pda.usdc_cust = None;  // No custom ATA initially
+    user_pda.cnctd_ata = ctx.accounts.cnctd_ata.key();
+    user_pda.cnctd_cust = None;  // No custom ATA initially
+    user_pda.music_ata = ctx.accounts.music_ata.key();
+    user_pda.music_cust = None;  // No custom ATA initially
+    user_pda.fees_waived = 0;
+    user_pda.waived_count = 0;
+    user_pda.bump = user_bump;
+    user_pda.version = UserPDA::VERSION;
+    
+    // Serialize back to the account
+    user_pda.try_serialize(&mut &mut ctx.accounts.user_pda.data.borrow_mut()[..])?;
 
+    Ok(())
+}
+
+fn create_missing_atas(ctx: &Context<InitializeUser>) -> Result<()> {
     if ctx.accounts.usdc_ata.data_is_empty() {
         // Create USDC ATA (regular Token program)
         msg!("Creating USDC ATA for user");
@@ -171,7 +311,11 @@ fn create_user_pda(ctx: &Context<InitializeUser>, args: &InitializeUserArgs) ->
         
         msg!("MUSIC ATA created: {}", ctx.accounts.music_ata.key());
     }
-    
+
+    Ok(())
+}
+
+fn deposit_usdc(ctx: &Context<InitializeUser>, args: &InitializeUserArgs) -> Result<()> {
     // Deposit USDC if requested
     if let Some(usdc_amount) = args.usdc_deposit_amount {
         if usdc_amount > 0 {
@@ -198,41 +342,6 @@ fn create_user_pda(ctx: &Context<InitializeUser>, args: &InitializeUserArgs) ->
             msg!("USDC deposited successfully");
         }
     }
-    
-    // Now transfer SOL from treasury to admin to reimburse them
-    let treasury_info = ctx.accounts.treasury.to_account_info();
-    let admin_info = ctx.accounts.admin.to_account_info();
 
-    let total_reimbursement = rent_lamports + fee_compensation;
-    
-    **treasury_info.try_borrow_mut_lamports()? -= total_reimbursement;
-    **admin_info.try_borrow_mut_lamports()? += total_reimbursement;
-    
-    msg!("Reimbursed admin {} lamports from treasury", total_reimbursement);
-    msg!("Final admin balance: {}", ctx.accounts.admin.lamports());
-    msg!("Final treasury balance: {}", ctx.accounts.treasury.to_account_info().lamports());
-    
-    // Initialize the UserPDA fields
-    let mut user_pda: UserPDA = UserPDA::try_deserialize_unchecked(
-        &mut &ctx.accounts.user_pda.data.borrow()[..]
-    )?;
-    
-    // Set the fields with actual ATA pubkeys
-    user_pda.admin = ctx.accounts.treasury.key();
-    user_pda.auth = None;
-    user_pda.usdc_ata = ctx.accounts.usdc_ata.key();
-    user_pda.usdc_cust = None;  // No custom ATA initially
-    user_pda.cnctd_ata = ctx.accounts.cnctd_ata.key();
-    user_pda.cnctd_cust = None;  // No custom ATA initially
-    user_pda.music_ata = ctx.accounts.music_ata.key();
-    user_pda.music_cust = None;  // No custom ATA initially
-    user_pda.fees_waived = 0;
-    user_pda.waived_count = 0;
-    user_pda.bump = user_bump;
-    
-    // Serialize back to the account
-    user_pda.try_serialize(&mut &mut ctx.accounts.user_pda.data.borrow_mut()[..])?;
-    
-    msg!("UserPDA initialized successfully");
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,85 @@
+use anchor_lang::{prelude::*, system_program::{transfer, Transfer}};
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{band_pda::BandPDA, release_access::ReleaseAccess, release_escrow::ReleaseEscrow, program_config::{PauseSubsystem, ProgramConfig}, treasury::{AdminRole, Treasury}, user_pda::UserPDA, versioning::Versioned},
+};
+
+/// Upgrades any versioned account to the current layout in place. The admin covers the extra rent
+/// when the account has to grow.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::AdminOps) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// CHECK: Any versioned account owned by this program, its type is read from the discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let discriminator = ctx.accounts.account.try_borrow_data()?
+        .get(..8)
+        .map(<[u8]>::to_vec)
+        .ok_or(error!(CnctdStudioError::UnsupportedAccountType))?;
+
+    match discriminator.as_slice() {
+        d if d == UserPDA::DISCRIMINATOR => migrate::<UserPDA>(ctx.accounts),
+        d if d == BandPDA::DISCRIMINATOR => migrate::<BandPDA>(ctx.accounts),
+        d if d == ReleaseEscrow::DISCRIMINATOR => migrate::<ReleaseEscrow>(ctx.accounts),
+        d if d == ReleaseAccess::DISCRIMINATOR => migrate::<ReleaseAccess>(ctx.accounts),
+        _ => err!(CnctdStudioError::UnsupportedAccountType),
+    }
+}
+
+fn migrate<T: Versioned>(accounts: &MigrateAccount) -> Result<()> {
+    let account = accounts.account.to_account_info();
+    let space = T::current_space();
+
+    // Grow the account first so fields appended since it was created read as zero
+    if account.data_len() < space {
+        let rent_due = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: accounts.admin.to_account_info(),
+                        to: account.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        account.realloc(space, true)?;
+        msg!("Reallocated {} to {} bytes", account.key(), space);
+    }
+
+    let mut state = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    let from_version = state.version();
+    require!(from_version < T::VERSION, CnctdStudioError::AccountAlreadyMigrated);
+
+    state.upgrade(account.key)?;
+    state.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+    msg!("Migrated {} from version {} to {}", account.key(), from_version, T::VERSION);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{token::{self, MintTo, Token}, token_2022::{self, MintTo as MintTo2022, Token2022}};
 
-use crate::{errors::CnctdStudioError, state::treasury::Treasury};
+use crate::{errors::CnctdStudioError, state::{emission_budget::EmissionBudget, governance::Governance, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}}};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
 pub struct MintTokensArgs {
@@ -11,8 +11,11 @@ pub struct MintTokensArgs {
 
 #[derive(Accounts)]
 pub struct MintTokens<'info> {
-    /// CHECK: The mint account for the token
-    #[account(mut)]
+    /// CHECK: The mint account for the token, must be CNCTD or MUSIC
+    #[account(
+        mut,
+        constraint = program_metadata.is_reward_mint(&mint.key()) @ CnctdStudioError::InvalidMint
+    )]
     pub mint: UncheckedAccount<'info>, // Token-2022 Mint Account
 
     /// CHECK: The destination account for the tokens
@@ -26,6 +29,33 @@ pub struct MintTokens<'info> {
     )]
     pub treasury: Account<'info, Treasury>, // PDA that is the mint authority
 
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::AdminOps) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"emission_budget", mint.key().as_ref()],
+        bump = emission_budget.bump
+    )]
+    pub emission_budget: Account<'info, EmissionBudget>, // Supply governance for the mint
+
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = !governance.requires_proposal() @ CnctdStudioError::MultisigApprovalRequired
+    )]
+    pub governance: Account<'info, Governance>, // Direct mints are only allowed without a multisig threshold
+
     #[account(signer)]
     pub signer: Signer<'info>,
 
@@ -35,57 +65,82 @@ pub struct MintTokens<'info> {
 }
 
 pub fn mint_tokens(ctx: Context<MintTokens>, data: MintTokensArgs) -> Result<()> {
-    let amount = data.amount;
     let standard = data.standard;
 
-    if !ctx.accounts.treasury.is_admin(&ctx.accounts.signer.key()) {
+    if !ctx.accounts.treasury.has_role(&ctx.accounts.signer.key(), AdminRole::Minter) {
         return Err(CnctdStudioError::Unauthorized.into());
     }
-    let bump = ctx.accounts.treasury.bump;
-    let seeds: &[&[u8]] = &[b"treasury", &[bump]];
-    let signer_seeds: &[&[&[u8]]] = &[seeds];
 
+    let amount = ctx.accounts.emission_budget.consume(data.amount, Clock::get()?.unix_timestamp)?;
     msg!("Minting {} tokens to {:?}", amount, ctx.accounts.destination.key());
 
+    let token_program = match standard {
+        true => ctx.accounts.token_program.to_account_info(),
+        false => ctx.accounts.token_program_2022.to_account_info(),
+    };
+
+    mint_from_treasury(
+        token_program,
+        standard,
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.destination.to_account_info(),
+        ctx.accounts.treasury.to_account_info(),
+        ctx.accounts.treasury.bump,
+        amount,
+    )
+}
+
+/// Mints `amount` tokens to `destination` with the treasury PDA as mint authority.
+/// Shared with multisig proposal execution.
+pub fn mint_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    standard: bool,
+    mint: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    treasury: AccountInfo<'info>,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"treasury", &[treasury_bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    let destination_key = destination.key();
+
     match standard {
         true => {
             let cpi_accounts = MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.destination.to_account_info(),
-                authority: ctx.accounts.treasury.to_account_info(),
+                mint,
+                to: destination,
+                authority: treasury,
             };
         
             token::mint_to(
                 CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
+                    token_program,
                     cpi_accounts,
                     signer_seeds,
                 ),
                 amount,
             )?;
-        
-            msg!("Successfully minted {} tokens to {:?}", amount, ctx.accounts.destination.key());
         }
         false => {
             let cpi_accounts = MintTo2022 {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.destination.to_account_info(),
-                authority: ctx.accounts.treasury.to_account_info(),
+                mint,
+                to: destination,
+                authority: treasury,
             };
         
             token_2022::mint_to(
                 CpiContext::new_with_signer(
-                    ctx.accounts.token_program_2022.to_account_info(),
+                    token_program,
                     cpi_accounts,
                     signer_seeds,
                 ),
                 amount,
             )?;
-        
-            msg!("Successfully minted {} tokens to {:?}", amount, ctx.accounts.destination.key());
         }   
     }
 
+    msg!("Successfully minted {} tokens to {:?}", amount, destination_key);
     
     Ok(())
 }
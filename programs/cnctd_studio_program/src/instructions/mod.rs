@@ -2,10 +2,9 @@
 pub mod initialize_treasury;
 pub mod get_treasury;
 pub mod close_treasury;
-// pub mod initialize_program_metadata;
-// pub mod update_program_metadata;
+pub mod update_program_metadata;
 // pub mod close_program_metadata;
-// pub mod initialize;
+pub mod initialize;
 pub mod initialize_token_mint;
 pub mod mint_tokens;
 pub mod add_metaplex_metadata;
@@ -14,8 +13,35 @@ pub mod initialize_band;
 pub mod transfer_tokens;
 // pub mod purchase_release;
 pub mod update_admins;
+pub mod set_admin_roles;
 pub mod close_user_account;
 pub mod release;
+pub mod initialize_reward_config;
+pub mod update_reward_config;
+pub mod initialize_emission_budget;
+pub mod update_emission_budget;
+pub mod get_emission_budget;
+pub mod initialize_governance;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod cancel_proposal;
+pub mod initialize_reimbursement_config;
+pub mod update_reimbursement_config;
+pub mod initialize_program_config;
+pub mod set_guardian;
+pub mod pause;
+pub mod unpause;
+pub mod migrate_account;
+pub mod bind_user_auth;
+pub mod set_custody_accounts;
+pub mod withdraw;
+pub mod add_band_member;
+pub mod remove_band_member;
+pub mod update_band_member_share;
+pub mod distribute_band_revenue;
+pub mod transfer_band_assets;
+pub mod close_band;
 // pub mod release_nft;
 // pub mod release_access;
 
@@ -23,10 +49,9 @@ pub mod release;
 pub use initialize_treasury::*;
 pub use get_treasury::*;
 pub use close_treasury::*;
-// pub use initialize_program_metadata::*;
-// pub use update_program_metadata::*;
+pub use update_program_metadata::*;
 // pub use close_program_metadata::*;
-// pub use initialize::*;
+pub use initialize::*;
 pub use initialize_token_mint::*;
 pub use mint_tokens::*;
 pub use add_metaplex_metadata::*;
@@ -35,7 +60,34 @@ pub use initialize_band::*;
 pub use transfer_tokens::*;
 // pub use purchase_release::*;
 pub use update_admins::*;
+pub use set_admin_roles::*;
 pub use close_user_account::*;
 pub use release::*;
+pub use initialize_reward_config::*;
+pub use update_reward_config::*;
+pub use initialize_emission_budget::*;
+pub use update_emission_budget::*;
+pub use get_emission_budget::*;
+pub use initialize_governance::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use cancel_proposal::*;
+pub use initialize_reimbursement_config::*;
+pub use update_reimbursement_config::*;
+pub use initialize_program_config::*;
+pub use set_guardian::*;
+pub use pause::*;
+pub use unpause::*;
+pub use migrate_account::*;
+pub use bind_user_auth::*;
+pub use set_custody_accounts::*;
+pub use withdraw::*;
+pub use add_band_member::*;
+pub use remove_band_member::*;
+pub use update_band_member_share::*;
+pub use distribute_band_revenue::*;
+pub use transfer_band_assets::*;
+pub use close_band::*;
 // pub use release_nft::*;
 // pub use release_access::*;
\ No newline at end of file
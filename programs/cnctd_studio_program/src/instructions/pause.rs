@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{program_config::{PauseSubsystem, ProgramConfig}, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Guardian or a super admin
+    #[account(
+        constraint = authority.key() == program_config.guardian
+            || treasury.has_role(&authority.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub fn pause(ctx: Context<Pause>, subsystems: Vec<PauseSubsystem>) -> Result<()> {
+    require!(!subsystems.is_empty(), CnctdStudioError::InvalidInput);
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.paused |= ProgramConfig::mask_of(&subsystems);
+    program_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Paused {:?} by {}", subsystems, ctx.accounts.authority.key());
+
+    Ok(())
+}
@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{arguments::release::CloseReleaseAccessArgs, state::{release_access::ReleaseAccess, treasury::Treasury }, utils::UuidFormatting};
+use crate::{errors::CnctdStudioError, arguments::release::CloseReleaseAccessArgs, state::{release_access::ReleaseAccess, reimbursement::{AdminUsage, ReimbursementConfig}, treasury::{AdminRole, Treasury} }};
 
 #[derive(Accounts)]
 #[instruction(args: CloseReleaseAccessArgs)]
 pub struct CloseReleaseAccess<'info> {
     /// Admin that is authorized to close the account
-    #[account(constraint = treasury.is_admin(&admin.key()))]
+    #[account(mut, constraint = treasury.has_role(&admin.key(), AdminRole::Operator))]
     pub admin: Signer<'info>,
         
     /// The treasury that will receive the reclaimed rent
@@ -16,16 +16,27 @@ pub struct CloseReleaseAccess<'info> {
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
     
     /// The user PDA being closed
     #[account(
         mut,
-        seeds = [
-            b"release_access",
-            args.release_id.to_7_byte_seed().as_ref(),
-            args.buyer_id.to_7_byte_seed().as_ref(),
-        ],
-        bump,
+        constraint = release_access.has_address(&release_access.key(), &args.release_id, &args.buyer_id)
+            @ CnctdStudioError::InvalidReleaseAccessAccount,
         close = treasury // Send lamports back to treasury
     )]
     pub release_access: Account<'info, ReleaseAccess>,
@@ -40,6 +51,8 @@ pub fn close_release_access(ctx: Context<CloseReleaseAccess>, args: CloseRelease
     ctx.accounts.treasury.reimburse_admin(
         &ctx.accounts.treasury.to_account_info(),
         &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
         args.fee_compensation,
         None,
     )?;
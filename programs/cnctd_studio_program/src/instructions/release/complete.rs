@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{token::{spl_token, Mint, TokenAccount}, token_2022::MintTo as MintTo2022};
+use anchor_spl::{associated_token::get_associated_token_address, token::{spl_token, Mint, Token, TokenAccount}, token_2022::Token2022};
 
 use crate::{
     arguments::release::CompleteReleaseArgs,
     errors::CnctdStudioError,
-    state::{release_escrow::ReleaseEscrow, treasury::Treasury, user_pda::UserPDA},
-    utils::UuidFormatting,
+    rewards::{artist_rewards, mint_reward, verify_cnctd_destination},
+    state::{emission_budget::EmissionBudget, release_escrow::ReleaseEscrow, reward_config::RewardConfig, reimbursement::{AdminUsage, ReimbursementConfig}, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA},
 };
 
 #[derive(Accounts)]
@@ -13,14 +13,13 @@ use crate::{
 pub struct CompleteRelease<'info> {
     #[account(
         mut,
-        constraint = treasury.is_admin(&admin.key()) @ CnctdStudioError::Unauthorized
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
     )]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"user", args.buyer_id.as_ref()],
-        bump
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
     )]
     pub buyer: Account<'info, UserPDA>,
 
@@ -31,25 +30,72 @@ pub struct CompleteRelease<'info> {
     )]
     pub treasury: Account<'info, Treasury>,
 
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Purchases) @ CnctdStudioError::ProgramPaused,
+        // Completion closes the escrow, so it waits for rewards to resume rather than dropping them
+        constraint = !program_config.is_paused(PauseSubsystem::Rewards) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
+
+    #[account(
+        seeds = [b"reward_config"],
+        bump = reward_config.bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
     
-    /// CHECK: CNCTD mint, using token 2022 program
-    #[account(mut)]
+    /// CHECK: CNCTD mint, using token 2022 program, checked against program metadata
+    #[account(mut, address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
     pub cnctd_mint: UncheckedAccount<'info>,
     
-    /// CHECK: MUSIC mint, using token 2022 program
-    #[account(mut)]
+    /// CHECK: MUSIC mint, using token 2022 program, checked against program metadata
+    #[account(mut, address = program_metadata.music_mint @ CnctdStudioError::InvalidMint)]
     pub music_mint: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"emission_budget", music_mint.key().as_ref()],
+        bump = music_budget.bump
+    )]
+    pub music_budget: Account<'info, EmissionBudget>,
+
+    #[account(
+        mut,
+        seeds = [b"emission_budget", cnctd_mint.key().as_ref()],
+        bump = cnctd_budget.bump
+    )]
+    pub cnctd_budget: Account<'info, EmissionBudget>,
+
     #[account(
         mut,
         close = treasury,
-        seeds = [
-            b"release_escrow",
-            args.release_id.to_7_byte_seed().as_ref(),
-            args.buyer_id.to_7_byte_seed().as_ref(),
-        ],
-        bump,
+        constraint = escrow.is_for(&args.release_id, &args.buyer_id) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = escrow.has_address(&escrow.key()) @ CnctdStudioError::InvalidEscrowAccount,
         constraint = escrow.fulfilled == true @ CnctdStudioError::EscrowNotFulfilled,
         constraint = escrow.payments_fulfilled == true @ CnctdStudioError::PaymentsNotFulfilled,
         constraint = escrow.nft_minted == true @ CnctdStudioError::NFTNotMinted,
@@ -57,34 +103,40 @@ pub struct CompleteRelease<'info> {
     pub escrow: Account<'info, ReleaseEscrow>,
 
     /// CHECK: Escrow USDC token account
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&escrow.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub escrow_usdc_ata: Account<'info, TokenAccount>,
     
-    /// CHECK: Buyer MUSIC token account
-    #[account(mut)]
+    /// CHECK: Buyer MUSIC token account, their custody account when set
+    #[account(
+        mut,
+        constraint = buyer.accepts_music_reward(&buyer.key(), &music_mint.key(), &buyer_music_ata.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub buyer_music_ata: UncheckedAccount<'info>,
 
-    /// CHECK: Standard token program (should be TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA)
-    pub standard_token_program: UncheckedAccount<'info>,
+    /// Standard token program, for the escrow USDC account
+    pub standard_token_program: Program<'info, Token>,
     
-    /// CHECK: Custom token program (for CNCTD and MUSIC)
-    pub token_2022_program: UncheckedAccount<'info>,
+    /// Token-2022 program, for CNCTD and MUSIC
+    pub token_2022_program: Program<'info, Token2022>,
     
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
 pub fn complete<'a, 'b, 'c, 'info>(
-    ctx: Context<'a, 'b, 'c, 'info, CompleteRelease<'info>>,
+    mut ctx: Context<'a, 'b, 'c, 'info, CompleteRelease<'info>>,
     args: CompleteReleaseArgs
 ) -> Result<()> {
     msg!("Complete Release Instruction");
     
-    // 1. Mint MUSIC tokens to buyer based on the amount they paid
-    mint_music_to_buyer(&ctx.accounts, &args)?;
+    // 1. Mint MUSIC tokens to buyer based on the amount they paid and the reward config
+    mint_music_to_buyer(&mut ctx.accounts)?;
     
     // 2. Mint CNCTD rewards to artists based on payment splits
-    mint_cnctd_to_artists(&ctx.accounts, ctx.remaining_accounts, &args)?;
+    mint_cnctd_to_artists(&mut ctx.accounts, ctx.remaining_accounts)?;
     
     // 3. Close the escrow USDC ATA and return lamports to treasury
     close_escrow_token_account(&ctx.accounts)?;
@@ -93,6 +145,8 @@ pub fn complete<'a, 'b, 'c, 'info>(
     ctx.accounts.treasury.reimburse_admin(
         &ctx.accounts.treasury.to_account_info(),
         &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
         args.fee_compensation,
         None, // No rent to reimburse as all accounts should already exist
     )?;
@@ -102,38 +156,30 @@ pub fn complete<'a, 'b, 'c, 'info>(
 }
 
 // Helper function to mint MUSIC tokens to buyer
-fn mint_music_to_buyer(accounts: &CompleteRelease, args: &CompleteReleaseArgs) -> Result<()> {
-    let music_amount = accounts.escrow.total_amount; // Same as USDC paid
+fn mint_music_to_buyer(accounts: &mut CompleteRelease) -> Result<()> {
+    let music_reward = accounts.reward_config.buyer_reward(accounts.escrow.total_amount)?;
+    let music_amount = accounts.music_budget.consume(music_reward, Clock::get()?.unix_timestamp)?;
     msg!("Minting {} MUSIC tokens to buyer", music_amount);
     
-    // Get treasury signer seeds
-    let treasury_signer_seeds: &[&[u8]] = &[b"treasury", &[accounts.treasury.bump]];
-    
-    // Using the MintTo2022 structure for token-2022 program
-    let cpi_accounts = MintTo2022 {
-        mint: accounts.music_mint.to_account_info(),
-        to: accounts.buyer_music_ata.to_account_info(),
-        authority: accounts.treasury.to_account_info(),
-    };
-    
-    // Use token_2022::mint_to for minting token-2022 tokens
-    anchor_spl::token_2022::mint_to(
-        CpiContext::new_with_signer(
-            accounts.token_2022_program.to_account_info(),
-            cpi_accounts,
-            &[treasury_signer_seeds],
-        ),
-        music_amount,
-    )?;
+    if music_amount == 0 {
+        msg!("MUSIC reward is zero, skipping");
+        return Ok(());
+    }
     
-    Ok(())
+    mint_reward(
+        &accounts.token_2022_program.to_account_info(),
+        &accounts.music_mint.to_account_info(),
+        &accounts.buyer_music_ata.to_account_info(),
+        &accounts.treasury.to_account_info(),
+        accounts.treasury.bump,
+        music_amount,
+    )
 }
 
 // Helper function to mint CNCTD tokens to artists
 fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
-    accounts: &CompleteRelease<'info>,
+    accounts: &mut CompleteRelease<'info>,
     remaining_accounts: &'a [AccountInfo<'info>],
-    args: &CompleteReleaseArgs
 ) -> Result<()> {
     // Calculate number of artists (payment splits excluding treasury fee)
     let artist_count = accounts.escrow.payment_splits.len();
@@ -144,39 +190,38 @@ fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
         return Ok(());
     }
     
-    // Calculate CNCTD reward per artist
-    let total_cnctd_reward = accounts.escrow.total_amount; // Total USDC amount paid for release
-    let cnctd_per_artist = total_cnctd_reward / (artist_count as u64);
-    msg!("Each artist will receive {} CNCTD tokens", cnctd_per_artist);
+    // Reward for the total USDC amount paid for release, shared in proportion to each artist's payment
+    let cnctd_reward_total = accounts.reward_config.artist_reward(accounts.escrow.total_amount)?;
+    let total_cnctd_reward = accounts.cnctd_budget.consume(cnctd_reward_total, Clock::get()?.unix_timestamp)?;
+    let cnctd_rewards = artist_rewards(total_cnctd_reward, &accounts.escrow.payment_splits)?;
     
-    // Get treasury signer seeds
-    let treasury_signer_seeds: &[&[u8]] = &[b"treasury", &[accounts.treasury.bump]];
-    
-    // Iterator for remaining accounts (artist CNCTD ATAs)
+    // Iterator for remaining accounts (artist CNCTD ATAs), followed by the UserPDAs of
+    // recipients paid at their custody account
     let mut remaining_accounts_iter = remaining_accounts.iter();
+    let mut user_accounts = remaining_accounts.get(artist_count..).unwrap_or_default().iter();
     
     // Process each payment split
-    for (i, split) in accounts.escrow.payment_splits.iter().enumerate() {
+    for (i, (split, cnctd_reward)) in accounts.escrow.payment_splits.iter().zip(cnctd_rewards).enumerate() {
         // Get the artist's CNCTD token account from remaining accounts
         let artist_cnctd_ata = next_account_info(&mut remaining_accounts_iter)?;
         
-        msg!("Minting {} CNCTD to artist {}", cnctd_per_artist, i + 1);
+        // Verify the recipient CNCTD account matches the escrow or its user's custody account
+        verify_cnctd_destination(artist_cnctd_ata, split, &accounts.cnctd_mint.key(), &mut user_accounts)?;
+        
+        if cnctd_reward == 0 {
+            msg!("CNCTD reward amount is zero for artist {}, skipping", i + 1);
+            continue;
+        }
         
-        // Using the MintTo2022 structure for token-2022 program
-        let cpi_accounts = MintTo2022 {
-            mint: accounts.cnctd_mint.to_account_info(),
-            to: artist_cnctd_ata.clone(),
-            authority: accounts.treasury.to_account_info(),
-        };
+        msg!("Minting {} CNCTD to artist {}", cnctd_reward, i + 1);
         
-        // Use token_2022::mint_to for minting token-2022 tokens
-        anchor_spl::token_2022::mint_to(
-            CpiContext::new_with_signer(
-                accounts.token_2022_program.to_account_info(),
-                cpi_accounts,
-                &[treasury_signer_seeds],
-            ),
-            cnctd_per_artist,
+        mint_reward(
+            &accounts.token_2022_program.to_account_info(),
+            &accounts.cnctd_mint.to_account_info(),
+            artist_cnctd_ata,
+            &accounts.treasury.to_account_info(),
+            accounts.treasury.bump,
+            cnctd_reward,
         )?;
     }
     
@@ -187,11 +232,10 @@ fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
 fn close_escrow_token_account(accounts: &CompleteRelease) -> Result<()> {
     msg!("Closing escrow USDC token account");
     
-    let release_seed = accounts.escrow.release_id.to_7_byte_seed();
-    let buyer_seed = accounts.escrow.buyer_id.to_7_byte_seed();
+    let (release_seed, buyer_seed) = accounts.escrow.signer_seed_ids(&accounts.escrow.key())?;
     
     // Get escrow signer seeds
-    let escrow_seeds = &[
+    let escrow_seeds: &[&[u8]] = &[
         b"release_escrow",
         release_seed.as_ref(),
         buyer_seed.as_ref(),
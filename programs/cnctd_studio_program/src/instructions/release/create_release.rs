@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    arguments::release::CreateReleaseArgs,
+    errors::CnctdStudioError,
+    state::{release::Release, reimbursement::{AdminUsage, ReimbursementConfig}, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA},
+    utils::{require_id_len, UuidFormatting},
+};
+
+#[derive(Accounts)]
+#[instruction(args: CreateReleaseArgs)]
+pub struct CreateRelease<'info> {
+    /// Admin or artist creating the release, becomes its authority
+    #[account(
+        mut,
+        constraint = treasury.has_role(&authority.key(), AdminRole::Operator)
+            || artist.is_some() @ CnctdStudioError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Purchases) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    /// Reimbursement tracking, only for admins: artists pay for their own releases
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", authority.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Option<Account<'info, AdminUsage>>,
+
+    /// Mint the release is priced in
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub currency_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Release::space(),
+        seeds = [b"release", args.release_id.to_solana_seed_format().as_bytes()],
+        bump
+    )]
+    pub release: Account<'info, Release>,
+
+    /// UserPDA of the artist creating the release, required when the authority isn't an admin
+    #[account(constraint = artist.auth == Some(authority.key()) @ CnctdStudioError::Unauthorized)]
+    pub artist: Option<Account<'info, UserPDA>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remaining accounts: the UserPDA or BandPDA of each user or band split recipient, in split order.
+/// BandPDAs must be writable, they count the releases paying them.
+pub fn create_release(ctx: Context<CreateRelease>, args: CreateReleaseArgs) -> Result<()> {
+    msg!("Creating release: {}", args.release_id);
+
+    require_id_len(&args.release_id)?;
+    require!(args.release_id.is_valid_uuid(), CnctdStudioError::InvalidInput);
+
+    let now = Clock::get()?.unix_timestamp;
+
+    let release = &mut ctx.accounts.release;
+    release.release_id = args.release_id.clone();
+    release.authority = ctx.accounts.authority.key();
+    release.price = args.price;
+    release.currency_mint = ctx.accounts.currency_mint.key();
+    release.treasury_fee_bps = ctx.accounts.program_metadata.treasury_fee_bps;
+    release.split_shares = args.split_shares.clone();
+    release.active = true;
+    release.created_at = now;
+    release.updated_at = now;
+    release.bump = ctx.bumps.release;
+
+    release.validate_terms()?;
+    release.verify_split_recipients(
+        ctx.remaining_accounts,
+        &ctx.accounts.program_metadata.usdc_mint,
+        &ctx.accounts.program_metadata.cnctd_mint,
+    )?;
+    Release::count_band_refs(&release.band_recipients(), ctx.remaining_accounts, true)?;
+
+    msg!("Release price: {}, treasury fee: {} bps, {} split shares",
+        release.price, release.treasury_fee_bps, release.split_shares.len());
+
+    // Admins are reimbursed by the treasury, artists pay for their own releases
+    if ctx.accounts.treasury.has_role(&ctx.accounts.authority.key(), AdminRole::Operator) {
+        let rent_lamports = Rent::get()?.minimum_balance(Release::space());
+        let admin_usage = ctx.accounts.admin_usage
+            .as_mut()
+            .ok_or(CnctdStudioError::NotEnoughAccounts)?;
+
+        ctx.accounts.treasury.reimburse_admin(
+            &ctx.accounts.treasury.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.reimbursement_config,
+            admin_usage,
+            args.fee_compensation,
+            Some(rent_lamports),
+        )?;
+    } else {
+        require!(ctx.accounts.admin_usage.is_none(), CnctdStudioError::InvalidInput);
+    }
+
+    msg!("Release created successfully");
+    Ok(())
+}
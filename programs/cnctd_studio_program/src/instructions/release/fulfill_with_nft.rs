@@ -1,47 +1,80 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, metadata::{mpl_token_metadata}, token::{Mint, Token, TokenAccount}};
+use anchor_spl::{associated_token::{get_associated_token_address, AssociatedToken}, metadata::{mpl_token_metadata}, token::{Mint, Token, TokenAccount}};
 
-use crate::{arguments::{metadata::Creator, release::FulfillReleaseArgs,}, errors::CnctdStudioError, state::{release_escrow::ReleaseEscrow, treasury::Treasury, user_pda::UserPDA}, utils::UuidFormatting};
+use crate::{arguments::{metadata::Creator, release::FulfillReleaseArgs,}, errors::CnctdStudioError, state::{release_escrow::ReleaseEscrow, reimbursement::{AdminUsage, ReimbursementConfig}, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA}};
 
 #[derive(Accounts)]
 #[instruction(args: FulfillReleaseArgs)]
 pub struct FulfillReleaseNFT<'info> {
     #[account(
         mut,
-        constraint = treasury.is_admin(&admin.key()) @ CnctdStudioError::Unauthorized
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
     )]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"user", args.buyer_id.as_ref()],
-        bump
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
     )]
     pub buyer: Account<'info, UserPDA>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
 
-    #[account(mut)]
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Purchases) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
+
+    /// USDC account receiving the treasury fee, as recorded in program metadata
+    #[account(
+        mut,
+        address = program_metadata.fee_recipient @ CnctdStudioError::InvalidTreasuryATA,
+        constraint = treasury_usdc_ata.mint == usdc_mint.key() @ CnctdStudioError::InvalidMint
+    )]
     pub treasury_usdc_ata: Account<'info, TokenAccount>,
 
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
 
     #[account(
         mut,
-        seeds = [
-            b"release_escrow",
-            args.release_id.to_7_byte_seed().as_ref(),
-            args.buyer_id.to_7_byte_seed().as_ref(),
-        ],
-        bump
+        constraint = escrow.is_for(&args.release_id, &args.buyer_id) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = escrow.has_address(&escrow.key()) @ CnctdStudioError::InvalidEscrowAccount,
     )]
     pub escrow: Account<'info, ReleaseEscrow>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&escrow.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub escrow_usdc_ata: Account<'info, TokenAccount>,
 
     #[account(
@@ -91,10 +124,10 @@ pub fn fulfill_with_nft<'a, 'b, 'c, 'info>(
     msg!("Fulfill Release Instruction");
     
     // 1. Pay the treasury fee from escrow
-    pay_treasury_fee(&mut ctx.accounts, &args)?;
+    pay_treasury_fee(&mut ctx.accounts)?;
 
     // 2. Pay each artist based on payment splits
-    pay_artists(&mut ctx.accounts, ctx.remaining_accounts, &args)?;
+    pay_artists(&mut ctx.accounts, ctx.remaining_accounts)?;
 
     let rent_sysvar = Rent::get()?;
     let total_rent = calculate_fulfill_rent_cost(&rent_sysvar);
@@ -112,6 +145,8 @@ pub fn fulfill_with_nft<'a, 'b, 'c, 'info>(
     ctx.accounts.treasury.reimburse_admin(
         &ctx.accounts.treasury.to_account_info(),
         &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
         args.fee_compensation,
         Some(total_rent), // reimburse for the rent calculated in mint_nft
     )?;
@@ -122,13 +157,12 @@ pub fn fulfill_with_nft<'a, 'b, 'c, 'info>(
 }
 
 // Helper function to pay treasury fee
-fn pay_treasury_fee(accounts: &mut FulfillReleaseNFT, args: &FulfillReleaseArgs) -> Result<()> {
+fn pay_treasury_fee(accounts: &mut FulfillReleaseNFT) -> Result<()> {
     msg!("Paying treasury fee: {} USDC", accounts.escrow.treasury_fee);
     
-    let release_seed = args.release_id.to_7_byte_seed();
-    let buyer_seed = args.buyer_id.to_7_byte_seed();
+    let (release_seed, buyer_seed) = accounts.escrow.signer_seed_ids(&accounts.escrow.key())?;
     // Transfer from escrow to treasury
-    let escrow_seeds = &[
+    let escrow_seeds: &[&[u8]] = &[
         b"release_escrow",
         release_seed.as_ref(),
         buyer_seed.as_ref(),
@@ -156,14 +190,12 @@ fn pay_treasury_fee(accounts: &mut FulfillReleaseNFT, args: &FulfillReleaseArgs)
 fn pay_artists<'a, 'b, 'c, 'info>(
     accounts: &mut FulfillReleaseNFT<'info>,
     remaining_accounts: &'a [AccountInfo<'info>],  // Note the explicit lifetimes
-    args: &FulfillReleaseArgs
 ) -> Result<()> {
     msg!("Processing payments to {} artists", accounts.escrow.payment_splits.len());
     
-    let release_seed = args.release_id.to_7_byte_seed();
-    let buyer_seed = args.buyer_id.to_7_byte_seed();
+    let (release_seed, buyer_seed) = accounts.escrow.signer_seed_ids(&accounts.escrow.key())?;
     // Get escrow signer seeds
-    let escrow_seeds = &[
+    let escrow_seeds: &[&[u8]] = &[
         b"release_escrow",
         release_seed.as_ref(),
         buyer_seed.as_ref(),
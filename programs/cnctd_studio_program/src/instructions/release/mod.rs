@@ -4,10 +4,18 @@ mod fulfill_with_access;
 mod complete;
 mod purchase_access;
 mod close_release_access;
+mod refund_escrow;
+mod reclaim_expired_escrow;
+mod create_release;
+mod update_release;
 
 pub use open_escrow::*;
 pub use fulfill_with_nft::*;
 pub use fulfill_with_access::*;
 pub use complete::*;
 pub use purchase_access::*;
-pub use close_release_access::*;
\ No newline at end of file
+pub use close_release_access::*;
+pub use refund_escrow::*;
+pub use reclaim_expired_escrow::*;
+pub use create_release::*;
+pub use update_release::*;
\ No newline at end of file
@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{get_associated_token_address, AssociatedToken},
     token::{transfer, Mint, Token, TokenAccount, Transfer},
 };
 
 use crate::{
     arguments::release::OpenEscrowArgs,
+    constants::{DEFAULT_ESCROW_FULFILLMENT_WINDOW, MAX_ESCROW_FULFILLMENT_WINDOW, MIN_ESCROW_FULFILLMENT_WINDOW},
     errors::CnctdStudioError,
-    state::{release_escrow::ReleaseEscrow, treasury::Treasury, user_pda::UserPDA},
-    utils::UuidFormatting,
+    pda,
+    state::{release::Release, release_escrow::ReleaseEscrow, reimbursement::{AdminUsage, ReimbursementConfig}, program_config::{PauseSubsystem, ProgramConfig}, treasury::{AdminRole, Treasury}, user_pda::UserPDA, versioning::Versioned},
+    utils::{require_id_len, UuidFormatting},
 };
 
 #[derive(Accounts)]
@@ -16,26 +18,59 @@ use crate::{
 pub struct OpenEscrow<'info> {
     #[account(
         mut,
-        constraint = treasury.is_admin(&admin.key()) @ CnctdStudioError::Unauthorized
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
     )]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"user", args.buyer_id.as_ref()],
-        bump
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
     )]
     pub buyer: Account<'info, UserPDA>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&buyer.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub buyer_usdc_ata: Account<'info, TokenAccount>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
 
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Purchases) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
+
+    #[account(
+        seeds = [b"release", args.release_id.to_solana_seed_format().as_bytes()],
+        bump = release.bump,
+        constraint = release.active @ CnctdStudioError::ReleaseInactive,
+    )]
+    pub release: Account<'info, Release>,
+
+    #[account(address = release.currency_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
 
     #[account(
@@ -44,13 +79,21 @@ pub struct OpenEscrow<'info> {
         payer = admin,
         seeds = [
             b"release_escrow",
-            args.release_id.to_7_byte_seed().as_ref(),
-            args.buyer_id.to_7_byte_seed().as_ref(),
+            args.release_id.to_uuid_bytes().as_ref(),
+            args.buyer_id.to_uuid_bytes().as_ref(),
         ],
         bump
     )]
     pub escrow: Account<'info, ReleaseEscrow>,
 
+    /// CHECK: Where an older escrow for this release and buyer would live. Must be unused, so a
+    /// purchase that is still held under the 7-byte prefix seeds can't be opened a second time.
+    #[account(
+        address = pda::legacy_release_escrow(&args.release_id, &args.buyer_id).0 @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = legacy_escrow.data_is_empty() @ CnctdStudioError::LegacyEscrowExists
+    )]
+    pub legacy_escrow: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = admin,
@@ -67,11 +110,22 @@ pub struct OpenEscrow<'info> {
 
 
 pub fn open_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()> {
+    require_id_len(&args.release_id)?;
+    require_id_len(&args.buyer_id)?;
+
+    // Seeds use the raw UUID bytes, which are only collision-free for well-formed UUIDs
+    require!(
+        args.release_id.is_valid_uuid() && args.buyer_id.is_valid_uuid(),
+        CnctdStudioError::InvalidInput
+    );
+
     let escrow = &mut ctx.accounts.escrow;
 
     msg!("Creating escrow for buyer: {:?}", ctx.accounts.buyer.key());
 
-    let total_payment = args.treasury_fee + args.payment_splits.iter().map(|split| split.amount).sum::<u64>();
+    // Amounts come from the on-chain release terms, not from the caller
+    let release = &ctx.accounts.release;
+    let total_payment = release.price;
 
     if escrow.total_amount > 0 {
         msg!("Escrow already funded with {} USDC.", escrow.total_amount);
@@ -79,21 +133,35 @@ pub fn open_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()>
         ctx.accounts.treasury.reimburse_admin(
             &ctx.accounts.treasury.to_account_info(),
             &ctx.accounts.admin.to_account_info(),
+            &ctx.accounts.reimbursement_config,
+            &mut ctx.accounts.admin_usage,
             args.fee_compensation,
-            Some(Rent::get()?.minimum_balance(82)),
+            None, // Nothing was created on this call
         )?;
         return Ok(());
     }
 
+    let fulfillment_window = args.fulfillment_window.unwrap_or(DEFAULT_ESCROW_FULFILLMENT_WINDOW);
+    require!(
+        (MIN_ESCROW_FULFILLMENT_WINDOW..=MAX_ESCROW_FULFILLMENT_WINDOW).contains(&fulfillment_window),
+        CnctdStudioError::InvalidFulfillmentWindow
+    );
+
     escrow.release_id = args.release_id.clone();
     escrow.buyer_id = args.buyer_id.clone();
-    escrow.treasury_fee = args.treasury_fee;
-    escrow.payment_splits = args.payment_splits.clone();
+    escrow.treasury_fee = release.treasury_fee()?;
+    escrow.payment_splits = release.payment_splits()?;
     escrow.total_amount = total_payment;
     escrow.fulfilled = false;
     escrow.purchase_date = args.purchase_date;
     escrow.bump = ctx.bumps.escrow;
+    escrow.version = ReleaseEscrow::VERSION;
+    // Deadline is based on the on-chain clock so the admin-supplied purchase date can't extend it
+    escrow.expires_at = Clock::get()?.unix_timestamp + fulfillment_window;
+    msg!("Escrow must be fulfilled before {}", escrow.expires_at);
   
+    let buyer_seed = ctx.accounts.buyer.signer_seed_id(&ctx.accounts.buyer.key(), &args.buyer_id)?;
+
     transfer(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
@@ -102,7 +170,7 @@ pub fn open_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()>
                 to: ctx.accounts.escrow_usdc_ata.to_account_info(),
                 authority: ctx.accounts.buyer.to_account_info(),
             },
-            &[&[b"user", args.buyer_id.as_ref(), &[ctx.accounts.buyer.bump]]],
+            &[&[b"user", buyer_seed.as_ref(), &[ctx.accounts.buyer.bump]]],
         ),
         total_payment,
     )?;
@@ -115,6 +183,8 @@ pub fn open_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()>
     ctx.accounts.treasury.reimburse_admin(
         &ctx.accounts.treasury.to_account_info(),
         &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
         args.fee_compensation,
         Some(total_rent),
     )?;
@@ -1,38 +1,83 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{associated_token::AssociatedToken, token::{Mint, Token, TokenAccount}, token_2022::Token2022};
+use anchor_spl::{associated_token::{get_associated_token_address, AssociatedToken}, token::{Mint, Token, TokenAccount}, token_2022::Token2022};
 
-use crate::{arguments::release::PurchaseAccessArgs, errors::CnctdStudioError, state::{release_access::ReleaseAccess, treasury::Treasury, user_pda::UserPDA}, utils::UuidFormatting};
+use crate::{arguments::release::PurchaseAccessArgs, errors::CnctdStudioError, pda, rewards::{artist_rewards, mint_reward, verify_cnctd_destination}, state::{emission_budget::EmissionBudget, release::Release, release_access::ReleaseAccess, reward_config::RewardConfig, reimbursement::{AdminUsage, ReimbursementConfig}, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA, versioning::Versioned}, utils::{require_id_len, UuidFormatting}};
 
 #[derive(Accounts)]
 #[instruction(args: PurchaseAccessArgs)]
 pub struct PurchaseAccess<'info> {
     #[account(
         mut,
-        constraint = treasury.is_admin(&admin.key()) @ CnctdStudioError::Unauthorized
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
     )]
     pub admin: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"user", args.buyer_id.as_ref()],
-        bump
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
     )]
     pub buyer: Account<'info, UserPDA>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        address = get_associated_token_address(&buyer.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub buyer_usdc_ata: Account<'info, TokenAccount>,
 
-    /// CHECK: Buyer MUSIC token account
-    #[account(mut)]
+    /// CHECK: Buyer MUSIC token account, their custody account when set
+    #[account(
+        mut,
+        constraint = buyer.accepts_music_reward(&buyer.key(), &music_mint.key(), &buyer_music_ata.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
     pub buyer_music_ata: UncheckedAccount<'info>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, Treasury>,
 
-    #[account(mut)]
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Purchases) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
+
+    #[account(
+        seeds = [b"reward_config"],
+        bump = reward_config.bump,
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+
+    /// USDC account receiving the treasury fee, as recorded in program metadata
+    #[account(
+        mut,
+        address = program_metadata.fee_recipient @ CnctdStudioError::InvalidTreasuryATA,
+        constraint = treasury_usdc_ata.mint == usdc_mint.key() @ CnctdStudioError::InvalidMint
+    )]
     pub treasury_usdc_ata: Account<'info, TokenAccount>,
 
     #[account(
@@ -41,23 +86,53 @@ pub struct PurchaseAccess<'info> {
         space = ReleaseAccess::space(),
         seeds = [
             b"release_access",
-            args.release_id.to_7_byte_seed().as_ref(),
-            args.buyer_id.to_7_byte_seed().as_ref(),
+            args.release_id.to_uuid_bytes().as_ref(),
+            args.buyer_id.to_uuid_bytes().as_ref(),
         ],
         bump,
     )]
     pub release_access: Account<'info, ReleaseAccess>, 
 
+    /// CHECK: Where an older access record for this release and buyer would live. Must be unused,
+    /// so a buyer never ends up with a second record under the new seeds.
+    #[account(
+        address = pda::legacy_release_access(&args.release_id, &args.buyer_id).0 @ CnctdStudioError::InvalidReleaseAccessAccount,
+        constraint = legacy_release_access.data_is_empty() @ CnctdStudioError::LegacyReleaseAccessExists
+    )]
+    pub legacy_release_access: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"release", args.release_id.to_solana_seed_format().as_bytes()],
+        bump = release.bump,
+        constraint = release.active @ CnctdStudioError::ReleaseInactive,
+    )]
+    pub release: Account<'info, Release>,
+
+    #[account(address = release.currency_mint @ CnctdStudioError::InvalidMint)]
     pub usdc_mint: Account<'info, Mint>,
     
-    /// CHECK: CNCTD mint, using token 2022 program
-    #[account(mut)]
+    /// CHECK: CNCTD mint, using token 2022 program, checked against program metadata
+    #[account(mut, address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
     pub cnctd_mint: UncheckedAccount<'info>,
     
-    /// CHECK: MUSIC mint, using token 2022 program
-    #[account(mut)]
+    /// CHECK: MUSIC mint, using token 2022 program, checked against program metadata
+    #[account(mut, address = program_metadata.music_mint @ CnctdStudioError::InvalidMint)]
     pub music_mint: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [b"emission_budget", music_mint.key().as_ref()],
+        bump = music_budget.bump
+    )]
+    pub music_budget: Account<'info, EmissionBudget>,
+
+    #[account(
+        mut,
+        seeds = [b"emission_budget", cnctd_mint.key().as_ref()],
+        bump = cnctd_budget.bump
+    )]
+    pub cnctd_budget: Account<'info, EmissionBudget>,
+
     pub token_program: Program<'info, Token>,
     pub token_2022_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -71,8 +146,20 @@ pub fn purchase_access<'a, 'b, 'c, 'info>(
 ) -> Result<()> {
     msg!("Purchase Access Instruction");
 
+    require_id_len(&args.release_id)?;
+    require_id_len(&args.buyer_id)?;
+
+    // Seeds use the raw UUID bytes, which are only collision-free for well-formed UUIDs
+    require!(
+        args.release_id.is_valid_uuid() && args.buyer_id.is_valid_uuid(),
+        CnctdStudioError::InvalidInput
+    );
+
+    // A fresh account has no release ID yet, only then was its rent paid on this call
+    let created = ctx.accounts.release_access.release_id.is_empty();
+
     // 1. Initialize the release access PDA
-    initialize_release_access(&mut ctx.accounts, &args)?;
+    initialize_release_access(&mut ctx.accounts, ctx.bumps.release_access, &args)?;
 
     // 2. Pay the treasury fee
     pay_treasury_fee(&mut ctx.accounts, &args)?;
@@ -80,21 +167,25 @@ pub fn purchase_access<'a, 'b, 'c, 'info>(
     // 3. Pay each artist based on payment splits
     pay_artists(&mut ctx.accounts, ctx.remaining_accounts, &args)?;
 
-    // 4. Mint MUSIC tokens to buyer
-    mint_music_to_buyer(&mut ctx.accounts, &args)?;
+    if !ctx.accounts.program_config.is_paused(PauseSubsystem::Rewards) {
+        // 4. Mint MUSIC tokens to buyer
+        mint_music_to_buyer(&mut ctx.accounts)?;
 
-    // 5. Mint CNCTD tokens to artists
-    mint_cnctd_to_artists(&mut ctx.accounts, ctx.remaining_accounts, &args)?;
+        // 5. Mint CNCTD tokens to artists
+        mint_cnctd_to_artists(&mut ctx.accounts, ctx.remaining_accounts)?;
+    } else {
+        msg!("Rewards are paused, skipping MUSIC and CNCTD rewards");
+    }
 
     // 6. Reimburse admin for transaction fees if specified
-    reimburse_admin(&mut ctx.accounts, &args)?;
+    reimburse_admin(&mut ctx.accounts, &args, created)?;
 
     msg!("Access purchase completed successfully");
 
     Ok(())
 }
 
-fn initialize_release_access(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) -> Result<()> {
+fn initialize_release_access(accounts: &mut PurchaseAccess, bump: u8, args: &PurchaseAccessArgs) -> Result<()> {
     msg!("Initializing Release Access PDA");
     
     let release_access = &mut accounts.release_access;
@@ -111,15 +202,19 @@ fn initialize_release_access(accounts: &mut PurchaseAccess, args: &PurchaseAcces
     
     release_access.expiration_date = args.expiration_date;
     msg!("Expiration Date: {:?}", release_access.expiration_date);
+
+    release_access.bump = bump;
+    release_access.version = ReleaseAccess::VERSION;
     
     Ok(())
 }
 
 fn pay_treasury_fee(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) -> Result<()> {
-    msg!("Paying treasury fee: {} USDC", args.treasury_fee);
+    let treasury_fee = accounts.release.treasury_fee()?;
+    msg!("Paying treasury fee: {} USDC", treasury_fee);
     
     // Skip if treasury fee is zero
-    if args.treasury_fee == 0 {
+    if treasury_fee == 0 {
         msg!("Treasury fee is zero, skipping payment");
         return Ok(());
     }
@@ -127,6 +222,8 @@ fn pay_treasury_fee(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) ->
     let treasury_usdc_ata = &accounts.treasury_usdc_ata;
 
     
+    let buyer_seed = accounts.buyer.signer_seed_id(&accounts.buyer.key(), &args.buyer_id)?;
+
     // Transfer from buyer to treasury
     anchor_spl::token::transfer(
         CpiContext::new_with_signer(
@@ -136,9 +233,9 @@ fn pay_treasury_fee(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) ->
                 to: treasury_usdc_ata.to_account_info(),
                 authority: accounts.buyer.to_account_info(),
             },
-            &[&[b"user", args.buyer_id.as_ref(), &[accounts.buyer.bump]]]
+            &[&[b"user", buyer_seed.as_ref(), &[accounts.buyer.bump]]]
         ),
-        args.treasury_fee
+        treasury_fee
     )?;
     
     msg!("Treasury fee payment successful");
@@ -151,16 +248,19 @@ fn pay_artists<'a, 'b, 'c, 'info>(
     remaining_accounts: &'a [AccountInfo<'info>],
     args: &PurchaseAccessArgs
 ) -> Result<()> {
-    msg!("Processing payments to {} artists", args.payment_splits.len());
+    let payment_splits = accounts.release.payment_splits()?;
+    msg!("Processing payments to {} artists", payment_splits.len());
     
     // Skip if no payment splits
-    if args.payment_splits.is_empty() {
+    if payment_splits.is_empty() {
         msg!("No artist payments to process");
         return Ok(());
     }
     
+    let buyer_seed = accounts.buyer.signer_seed_id(&accounts.buyer.key(), &args.buyer_id)?;
+
     // Process each payment split
-    for (i, split) in args.payment_splits.iter().enumerate() {
+    for (i, split) in payment_splits.iter().enumerate() {
         // Get the recipient's token account from remaining accounts
         let recipient_ata = &remaining_accounts[i];
         
@@ -187,7 +287,7 @@ fn pay_artists<'a, 'b, 'c, 'info>(
                     to: recipient_ata.clone(),
                     authority: accounts.buyer.to_account_info(),
                 },
-                &[&[b"user", args.buyer_id.as_ref(), &[accounts.buyer.bump]]]
+                &[&[b"user", buyer_seed.as_ref(), &[accounts.buyer.bump]]]
             ),
             split.amount
         )?;
@@ -198,32 +298,26 @@ fn pay_artists<'a, 'b, 'c, 'info>(
     Ok(())
 }
 
-fn mint_music_to_buyer(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) -> Result<()> {
-    // Calculate total payment amount (treasury fee + all artist payments)
-    let total_payment = args.treasury_fee + args.payment_splits.iter().map(|split| split.amount).sum::<u64>();
-    msg!("Minting {} MUSIC tokens to buyer", total_payment);
+fn mint_music_to_buyer(accounts: &mut PurchaseAccess) -> Result<()> {
+    // Reward is based on the release price (treasury fee + all artist payments)
+    let music_reward = accounts.reward_config.buyer_reward(accounts.release.price)?;
+    let music_amount = accounts.music_budget.consume(music_reward, Clock::get()?.unix_timestamp)?;
+    msg!("Minting {} MUSIC tokens to buyer", music_amount);
     
-    // Skip if total payment is zero
-    if total_payment == 0 {
-        msg!("Total payment is zero, skipping MUSIC minting");
+    // Skip if the reward is zero
+    if music_amount == 0 {
+        msg!("MUSIC reward is zero, skipping MUSIC minting");
         return Ok(());
     }
     
-    // Get treasury signer seeds
-    let treasury_seeds: &[&[u8]] = &[b"treasury", &[accounts.treasury.bump]];
-    
     // Mint MUSIC tokens to buyer
-    anchor_spl::token_2022::mint_to(
-        CpiContext::new_with_signer(
-            accounts.token_2022_program.to_account_info(),
-            anchor_spl::token_2022::MintTo {
-                mint: accounts.music_mint.to_account_info(),
-                to: accounts.buyer_music_ata.to_account_info(),
-                authority: accounts.treasury.to_account_info(),
-            },
-            &[treasury_seeds]
-        ),
-        total_payment
+    mint_reward(
+        &accounts.token_2022_program.to_account_info(),
+        &accounts.music_mint.to_account_info(),
+        &accounts.buyer_music_ata.to_account_info(),
+        &accounts.treasury.to_account_info(),
+        accounts.treasury.bump,
+        music_amount,
     )?;
     
     msg!("MUSIC tokens minted successfully");
@@ -234,55 +328,43 @@ fn mint_music_to_buyer(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs)
 fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
     accounts: &mut PurchaseAccess<'info>,
     remaining_accounts: &'a [AccountInfo<'info>],
-    args: &PurchaseAccessArgs
 ) -> Result<()> {
+    let payment_splits = accounts.release.payment_splits()?;
+
     // Calculate number of artists (payment splits)
-    let artist_count = args.payment_splits.len();
+    let artist_count = payment_splits.len();
     
     if artist_count == 0 {
         msg!("No artists to mint CNCTD rewards to");
         return Ok(());
     }
     
-    // Calculate total payment amount (including treasury fee)
-    let total_payment = args.treasury_fee + args.payment_splits.iter().map(|split| split.amount).sum::<u64>();
+    // Reward for the release price (including treasury fee)
+    let cnctd_reward_total = accounts.reward_config.artist_reward(accounts.release.price)?;
+    let total_cnctd_reward = accounts.cnctd_budget.consume(cnctd_reward_total, Clock::get()?.unix_timestamp)?;
     
-    // Calculate proportional rewards for each artist
-    // Each artist gets rewarded based on their proportion of the artist payments, but applied to the total payment
-    let total_artist_payments = args.payment_splits.iter().map(|split| split.amount).sum::<u64>();
+    // Each artist is rewarded their proportion of the artist payments, applied to the total reward
+    let cnctd_rewards = artist_rewards(total_cnctd_reward, &payment_splits)?;
     
     msg!("Minting CNCTD rewards to {} artists", artist_count);
     
-    // Get treasury signer seeds
-    let treasury_seeds: &[&[u8]] = &[b"treasury", &[accounts.treasury.bump]];
-    
     // Skip the artist USDC ATAs to get to CNCTD ATAs
     let cnctd_atas_start = artist_count;
+    require!(
+        remaining_accounts.len() >= cnctd_atas_start + artist_count,
+        CnctdStudioError::NotEnoughAccounts
+    );
+
+    // UserPDAs of recipients paid at their custody account follow the CNCTD ATAs
+    let mut user_accounts = remaining_accounts[cnctd_atas_start + artist_count..].iter();
     
     // Process each payment split
-    for (i, split) in args.payment_splits.iter().enumerate() {
+    for (i, (split, cnctd_reward)) in payment_splits.iter().zip(cnctd_rewards).enumerate() {
         // Get the artist's CNCTD token account from the remaining accounts
         let artist_cnctd_ata = &remaining_accounts[cnctd_atas_start + i];
         
-        // Verify the recipient CNCTD account matches what's in the payment split
-        require!(
-            artist_cnctd_ata.key() == split.recipient_cnctd_ata,
-            CnctdStudioError::InvalidPaymentReceiver
-        );
-        
-        // Calculate CNCTD reward based on artist's proportion of the total payment
-        // This gives them their share of the entire payment including treasury fee
-        let artist_proportion = if total_artist_payments > 0 {
-            (split.amount as f64) / (total_artist_payments as f64)
-        } else {
-            0.0
-        };
-        
-        let cnctd_reward = if total_artist_payments > 0 {
-            (artist_proportion * (total_payment as f64)).round() as u64
-        } else {
-            0
-        };
+        // Verify the recipient CNCTD account matches the payment split or its user's custody account
+        verify_cnctd_destination(artist_cnctd_ata, split, &accounts.cnctd_mint.key(), &mut user_accounts)?;
         
         // Skip if reward amount is zero
         if cnctd_reward == 0 {
@@ -290,21 +372,16 @@ fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
             continue;
         }
         
-        msg!("Minting {} CNCTD to artist {} ({}% of total)", 
-            cnctd_reward, i + 1, (artist_proportion * 100.0).round() as u64);
+        msg!("Minting {} CNCTD to artist {}", cnctd_reward, i + 1);
         
         // Mint CNCTD tokens to artist
-        anchor_spl::token_2022::mint_to(
-            CpiContext::new_with_signer(
-                accounts.token_2022_program.to_account_info(),
-                anchor_spl::token_2022::MintTo {
-                    mint: accounts.cnctd_mint.to_account_info(),
-                    to: artist_cnctd_ata.clone(),
-                    authority: accounts.treasury.to_account_info(),
-                },
-                &[treasury_seeds]
-            ),
-            cnctd_reward
+        mint_reward(
+            &accounts.token_2022_program.to_account_info(),
+            &accounts.cnctd_mint.to_account_info(),
+            artist_cnctd_ata,
+            &accounts.treasury.to_account_info(),
+            accounts.treasury.bump,
+            cnctd_reward,
         )?;
     }
     
@@ -312,10 +389,13 @@ fn mint_cnctd_to_artists<'a, 'b, 'c, 'info>(
     Ok(())
 }
 
-fn reimburse_admin(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) -> Result<()> {
-    // Calculate rent for ReleaseAccess account
-    let rent = Rent::get()?;
-    let release_access_rent = rent.minimum_balance(ReleaseAccess::space());
+fn reimburse_admin(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs, created: bool) -> Result<()> {
+    // Calculate rent for ReleaseAccess account, if this call paid for it
+    let release_access_rent = if created {
+        Some(Rent::get()?.minimum_balance(ReleaseAccess::space()))
+    } else {
+        None
+    };
     
     msg!("Reimbursing admin for transaction costs");
     
@@ -323,8 +403,10 @@ fn reimburse_admin(accounts: &mut PurchaseAccess, args: &PurchaseAccessArgs) ->
     accounts.treasury.reimburse_admin(
         &accounts.treasury.to_account_info(),
         &accounts.admin.to_account_info(),
+        &accounts.reimbursement_config,
+        &mut accounts.admin_usage,
         args.fee_compensation,
-        Some(release_access_rent),
+        release_access_rent,
     )?;
     
     msg!("Admin reimbursed successfully");
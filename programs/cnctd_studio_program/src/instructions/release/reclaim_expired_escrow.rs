@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token::{Mint, Token, TokenAccount}};
+
+use crate::{
+    arguments::release::ReclaimExpiredEscrowArgs,
+    errors::CnctdStudioError,
+    state::{release_escrow::ReleaseEscrow, program_metadata::ProgramMetadata, treasury::Treasury, user_pda::UserPDA},
+};
+
+/// Permissionless: anyone can crank an expired, unfulfilled escrow back to the buyer.
+#[derive(Accounts)]
+#[instruction(args: ReclaimExpiredEscrowArgs)]
+pub struct ReclaimExpiredEscrow<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
+    )]
+    pub buyer: Account<'info, UserPDA>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address(&buyer.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub buyer_usdc_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = treasury,
+        constraint = escrow.is_for(&args.release_id, &args.buyer_id) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = escrow.has_address(&escrow.key()) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = !escrow.payments_fulfilled @ CnctdStudioError::EscrowAlreadyFulfilled,
+    )]
+    pub escrow: Account<'info, ReleaseEscrow>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = escrow,
+    )]
+    pub escrow_usdc_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reclaim_expired_escrow(ctx: Context<ReclaimExpiredEscrow>, _args: ReclaimExpiredEscrowArgs) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let deadline = ctx.accounts.escrow.fulfillment_deadline();
+
+    msg!("Reclaiming escrow {} (deadline {}, now {})", ctx.accounts.escrow.key(), deadline, now);
+
+    require!(
+        ctx.accounts.escrow.is_expired(now),
+        CnctdStudioError::EscrowNotExpired
+    );
+
+    // Return the USDC to the buyer and close the escrow USDC ATA.
+    // The escrow account itself is closed by Anchor via the `close = treasury` constraint
+    ctx.accounts.escrow.refund_buyer(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.escrow_usdc_ata.to_account_info(),
+        &ctx.accounts.buyer_usdc_ata.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    msg!("Expired escrow reclaimed by {}", ctx.accounts.cranker.key());
+    Ok(())
+}
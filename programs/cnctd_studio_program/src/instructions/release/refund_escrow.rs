@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token::{Mint, Token, TokenAccount}};
+
+use crate::{
+    arguments::release::RefundEscrowArgs,
+    errors::CnctdStudioError,
+    state::{release_escrow::ReleaseEscrow, reimbursement::{AdminUsage, ReimbursementConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}, user_pda::UserPDA},
+};
+
+#[derive(Accounts)]
+#[instruction(args: RefundEscrowArgs)]
+pub struct RefundEscrow<'info> {
+    #[account(
+        mut,
+        constraint = treasury.has_role(&admin.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = buyer.has_address(&buyer.key(), &args.buyer_id) @ CnctdStudioError::InvalidUserAccount
+    )]
+    pub buyer: Account<'info, UserPDA>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address(&buyer.key(), &usdc_mint.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub buyer_usdc_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminUsage::space(),
+        seeds = [b"admin_usage", admin.key().as_ref()],
+        bump
+    )]
+    pub admin_usage: Account<'info, AdminUsage>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = treasury,
+        constraint = escrow.is_for(&args.release_id, &args.buyer_id) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = escrow.has_address(&escrow.key()) @ CnctdStudioError::InvalidEscrowAccount,
+        constraint = !escrow.payments_fulfilled @ CnctdStudioError::EscrowAlreadyFulfilled,
+    )]
+    pub escrow: Account<'info, ReleaseEscrow>,
+
+    #[account(
+        mut,
+        associated_token::mint = usdc_mint,
+        associated_token::authority = escrow,
+    )]
+    pub escrow_usdc_ata: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn refund_escrow(ctx: Context<RefundEscrow>, args: RefundEscrowArgs) -> Result<()> {
+    msg!("Refunding escrow {} to buyer {}", ctx.accounts.escrow.key(), ctx.accounts.buyer.key());
+
+    // 1. Return the USDC to the buyer and close the escrow USDC ATA
+    ctx.accounts.escrow.refund_buyer(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.escrow_usdc_ata.to_account_info(),
+        &ctx.accounts.buyer_usdc_ata.to_account_info(),
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    // 2. Reimburse admin for transaction fees if specified
+    // The escrow account itself is closed by Anchor via the `close = treasury` constraint
+    ctx.accounts.treasury.reimburse_admin(
+        &ctx.accounts.treasury.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.reimbursement_config,
+        &mut ctx.accounts.admin_usage,
+        args.fee_compensation,
+        None,
+    )?;
+
+    msg!("Escrow refunded successfully");
+    Ok(())
+}
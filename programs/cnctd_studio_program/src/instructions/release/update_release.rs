@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::release::UpdateReleaseArgs,
+    errors::CnctdStudioError,
+    state::{program_metadata::ProgramMetadata, release::Release, treasury::{AdminRole, Treasury}, user_pda::UserPDA},
+    utils::UuidFormatting,
+};
+
+#[derive(Accounts)]
+#[instruction(args: UpdateReleaseArgs)]
+pub struct UpdateRelease<'info> {
+    /// Release authority or a treasury admin
+    #[account(
+        constraint = authority.key() == release.authority
+            || treasury.has_role(&authority.key(), AdminRole::Operator) @ CnctdStudioError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"release", args.release_id.to_solana_seed_format().as_bytes()],
+        bump = release.bump,
+    )]
+    pub release: Account<'info, Release>,
+
+    /// UserPDA of the artist taking over the release, required when the new authority isn't an admin
+    pub new_authority_artist: Option<Account<'info, UserPDA>>,
+}
+
+/// Remaining accounts when changing splits: the UserPDA or BandPDA of each user or band
+/// split recipient, in split order, followed by the BandPDA of any band only the old splits paid.
+/// BandPDAs must be writable, they count the releases paying them.
+pub fn update_release(ctx: Context<UpdateRelease>, args: UpdateReleaseArgs) -> Result<()> {
+    msg!("Updating release: {}", args.release_id);
+
+    let release = &mut ctx.accounts.release;
+
+    if let Some(price) = args.price {
+        release.price = price;
+    }
+    if let Some(treasury_fee_bps) = args.treasury_fee_bps {
+        require!(
+            ctx.accounts.treasury.has_role(&ctx.accounts.authority.key(), AdminRole::Treasurer),
+            CnctdStudioError::Unauthorized
+        );
+        release.treasury_fee_bps = treasury_fee_bps;
+    }
+    let splits_changed = args.split_shares.is_some();
+    let old_bands = release.band_recipients();
+    if let Some(split_shares) = args.split_shares {
+        release.split_shares = split_shares;
+    }
+    if let Some(active) = args.active {
+        release.active = active;
+    }
+    if let Some(new_authority) = args.new_authority {
+        let is_artist = ctx.accounts.new_authority_artist.as_ref()
+            .is_some_and(|artist| artist.auth == Some(new_authority));
+        require!(
+            ctx.accounts.treasury.has_role(&new_authority, AdminRole::Operator) || is_artist,
+            CnctdStudioError::Unauthorized
+        );
+        msg!("Transferring release authority to {}", new_authority);
+        release.authority = new_authority;
+    }
+    release.updated_at = Clock::get()?.unix_timestamp;
+
+    release.validate_terms()?;
+    if splits_changed {
+        release.verify_split_recipients(
+            ctx.remaining_accounts,
+            &ctx.accounts.program_metadata.usdc_mint,
+            &ctx.accounts.program_metadata.cnctd_mint,
+        )?;
+        Release::count_band_refs(&old_bands, ctx.remaining_accounts, false)?;
+        Release::count_band_refs(&release.band_recipients(), ctx.remaining_accounts, true)?;
+    }
+
+    msg!("Release price: {}, treasury fee: {} bps, {} split shares, active: {}",
+        release.price, release.treasury_fee_bps, release.split_shares.len(), release.active);
+
+    Ok(())
+}
@@ -31,6 +31,7 @@ pub struct OpenEscrow<'info> {
     pub buyer_usdc_ata: Account<'info, TokenAccount>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
@@ -80,7 +81,7 @@ pub fn open_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()>
             &ctx.accounts.treasury.to_account_info(),
             &ctx.accounts.admin.to_account_info(),
             args.fee_compensation,
-            Some(Rent::get()?.minimum_balance(82)),
+            None, // Nothing was created on this call
         )?;
         return Ok(());
     }
@@ -20,6 +20,7 @@ pub struct FulfillRelease<'info> {
     pub buyer: Account<'info, UserPDA>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
@@ -31,6 +31,7 @@ pub struct OpenEscrow<'info> {
     pub buyer_usdc_ata: Account<'info, TokenAccount>,
 
     #[account(
+        mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
     )]
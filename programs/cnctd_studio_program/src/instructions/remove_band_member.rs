@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CnctdStudioError, state::{band_pda::BandPDA, treasury::{AdminRole, Treasury}}};
+
+/// Members are removed by key, so a member whose user account has since been closed can still go
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct RemoveBandMember<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount,
+    )]
+    pub band_pda: Account<'info, BandPDA>,
+}
+
+pub fn remove_band_member(ctx: Context<RemoveBandMember>, _band_id: String, member: Pubkey) -> Result<()> {
+    let band = &mut ctx.accounts.band_pda;
+
+    let index = band.member_index(&member).ok_or(error!(CnctdStudioError::BandMemberNotFound))?;
+    band.members.remove(index);
+
+    msg!("Removed member {} from band {} ({} bps allocated)", member, band.key(), band.total_share_bps());
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::{errors::CnctdStudioError, state::{governance::Governance, treasury::{AdminRole, AdminRoles, Treasury}}};
+
+#[derive(Accounts)]
+pub struct SetAdminRoles<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        realloc = Treasury::space(),
+        realloc::payer = current_admin,
+        realloc::zero = false,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// The super admin granting the roles (must be a signer)
+    #[account(
+        mut,
+        constraint = treasury.has_role(&current_admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub current_admin: Signer<'info>,
+
+    /// The admin whose roles are replaced (doesn't need to sign)
+    /// CHECK: We're just using this as a pubkey reference
+    pub target_admin: UncheckedAccount<'info>,
+
+    /// Direct role changes are only allowed without a multisig threshold or admin change delay
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = !governance.requires_proposal() @ CnctdStudioError::MultisigApprovalRequired,
+        constraint = !governance.requires_queued_admin_change() @ CnctdStudioError::AdminChangeQueued
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_admin_roles(ctx: Context<SetAdminRoles>, roles: Vec<AdminRole>) -> Result<()> {
+    let target_key = ctx.accounts.target_admin.key();
+    let mask = roles.iter().fold(0u8, |mask, role| mask | role.mask());
+
+    apply_admin_roles(&mut ctx.accounts.treasury, target_key, mask)?;
+
+    msg!("Set roles for admin {}: {:?}", target_key, roles);
+
+    Ok(())
+}
+
+/// Replaces the role grant of `target_key` with `mask`. Shared with multisig proposal execution.
+pub fn apply_admin_roles(treasury: &mut Treasury, target_key: Pubkey, mask: u8) -> Result<()> {
+    require!(treasury.is_admin(&target_key), CnctdStudioError::AdminNotFound);
+
+    treasury.roles.retain(|grant| grant.admin != target_key);
+    if mask != 0 {
+        treasury.roles.push(AdminRoles { admin: target_key, roles: mask });
+    }
+
+    // The first grant switches the treasury over to roles, so it must leave a super admin behind
+    require!(treasury.super_admin_count() > 0, CnctdStudioError::CannotRemoveLastSuperAdmin);
+
+    msg!("Admin {} role mask set to {:#06b}", target_key, mask);
+
+    Ok(())
+}
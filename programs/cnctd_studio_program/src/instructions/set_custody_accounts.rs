@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{errors::CnctdStudioError, state::{program_metadata::ProgramMetadata, user_pda::UserPDA}};
+
+/// Lets the wallet bound as a user's `auth` choose where their tokens go. Each custody account
+/// must be held by that wallet; leaving one out reverts to the program-derived ATA.
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct SetCustodyAccounts<'info> {
+    pub auth: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = user_pda.has_address(&user_pda.key(), &user_id) @ CnctdStudioError::InvalidUserAccount,
+        constraint = user_pda.auth == Some(auth.key()) @ CnctdStudioError::Unauthorized,
+    )]
+    pub user_pda: Account<'info, UserPDA>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        constraint = usdc_cust.mint == program_metadata.usdc_mint @ CnctdStudioError::InvalidMint,
+        constraint = usdc_cust.owner == auth.key() @ CnctdStudioError::InvalidTokenAccountOwner,
+    )]
+    pub usdc_cust: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = cnctd_cust.mint == program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint,
+        constraint = cnctd_cust.owner == auth.key() @ CnctdStudioError::InvalidTokenAccountOwner,
+    )]
+    pub cnctd_cust: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = music_cust.mint == program_metadata.music_mint @ CnctdStudioError::InvalidMint,
+        constraint = music_cust.owner == auth.key() @ CnctdStudioError::InvalidTokenAccountOwner,
+    )]
+    pub music_cust: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+pub fn set_custody_accounts(ctx: Context<SetCustodyAccounts>, _user_id: String) -> Result<()> {
+    let user_pda = &mut ctx.accounts.user_pda;
+    user_pda.usdc_cust = ctx.accounts.usdc_cust.as_ref().map(|account| account.key());
+    user_pda.cnctd_cust = ctx.accounts.cnctd_cust.as_ref().map(|account| account.key());
+    user_pda.music_cust = ctx.accounts.music_cust.as_ref().map(|account| account.key());
+
+    msg!("User {} custody accounts - USDC: {:?}, CNCTD: {:?}, MUSIC: {:?}",
+        user_pda.key(), user_pda.usdc_cust, user_pda.cnctd_cust, user_pda.music_cust);
+
+    Ok(())
+}
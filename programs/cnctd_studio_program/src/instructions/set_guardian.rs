@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{program_config::ProgramConfig, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let program_config = &mut ctx.accounts.program_config;
+
+    msg!("Guardian changed from {} to {}", program_config.guardian, guardian);
+
+    program_config.guardian = guardian;
+    program_config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
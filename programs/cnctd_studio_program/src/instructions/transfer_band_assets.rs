@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+};
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{band_pda::BandPDA, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}},
+};
+
+/// Empties the band's USDC and CNCTD ATAs ahead of `close_band`. Each member gets their share,
+/// anything the shares leave unallocated goes to the treasury.
+/// Remaining accounts: each member's UserPDA USDC ATA, then each member's UserPDA CNCTD ATA,
+/// both in the order of `band_pda.members`.
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct TransferBandAssets<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::AdminOps) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount)]
+    pub band_pda: Account<'info, BandPDA>,
+
+    #[account(address = program_metadata.usdc_mint @ CnctdStudioError::InvalidMint)]
+    pub usdc_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = program_metadata.cnctd_mint @ CnctdStudioError::InvalidMint)]
+    pub cnctd_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&band_pda.key(), &usdc_mint.key(), &token_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub band_usdc_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&band_pda.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub band_cnctd_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&treasury.key(), &usdc_mint.key(), &token_program.key()) @ CnctdStudioError::InvalidTreasuryATA
+    )]
+    pub treasury_usdc_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&treasury.key(), &cnctd_mint.key(), &token_2022_program.key()) @ CnctdStudioError::InvalidTreasuryATA
+    )]
+    pub treasury_cnctd_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn transfer_band_assets<'info>(
+    ctx: Context<'_, '_, '_, 'info, TransferBandAssets<'info>>,
+    band_id: String,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let member_count = accounts.band_pda.members.len();
+
+    msg!("Transferring assets of band {} to {} members", accounts.band_pda.key(), member_count);
+
+    require!(
+        ctx.remaining_accounts.len() >= member_count * 2,
+        CnctdStudioError::NotEnoughAccounts
+    );
+    let (member_usdc_atas, rest) = ctx.remaining_accounts.split_at(member_count);
+    let member_cnctd_atas = &rest[..member_count];
+
+    let band_seed = accounts.band_pda.signer_seed_id(&accounts.band_pda.key(), &band_id)?;
+    let band_seeds: &[&[u8]] = &[b"band", band_seed.as_ref(), &[accounts.band_pda.bump]];
+
+    sweep(
+        accounts,
+        band_seeds,
+        &accounts.band_usdc_ata,
+        &accounts.usdc_mint,
+        member_usdc_atas,
+        &accounts.treasury_usdc_ata,
+        accounts.token_program.to_account_info(),
+    )?;
+    sweep(
+        accounts,
+        band_seeds,
+        &accounts.band_cnctd_ata,
+        &accounts.cnctd_mint,
+        member_cnctd_atas,
+        &accounts.treasury_cnctd_ata,
+        accounts.token_2022_program.to_account_info(),
+    )?;
+
+    msg!("Band assets transferred");
+    Ok(())
+}
+
+fn sweep<'info>(
+    accounts: &TransferBandAssets<'info>,
+    band_seeds: &[&[u8]],
+    source: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    member_atas: &[AccountInfo<'info>],
+    treasury_ata: &InterfaceAccount<'info, TokenAccount>,
+    token_program: AccountInfo<'info>,
+) -> Result<()> {
+    if source.amount == 0 {
+        msg!("Band balance of mint {} is zero, skipping", mint.key());
+        return Ok(());
+    }
+
+    let (member_amounts, unallocated) = accounts.band_pda.member_amounts(source.amount)?;
+
+    let recipients = accounts.band_pda.members.iter().zip(member_atas).zip(member_amounts);
+    for ((member, recipient_ata), amount) in recipients {
+        require_keys_eq!(
+            recipient_ata.key(),
+            get_associated_token_address_with_program_id(&member.user_pda, &mint.key(), token_program.key),
+            CnctdStudioError::InvalidPaymentReceiver
+        );
+        send(accounts, band_seeds, source, mint, recipient_ata.clone(), token_program.clone(), amount)?;
+        msg!("Sent {} of mint {} to member {}", amount, mint.key(), member.user_pda);
+    }
+
+    send(accounts, band_seeds, source, mint, treasury_ata.to_account_info(), token_program, unallocated)?;
+    msg!("Sent {} of mint {} to the treasury", unallocated, mint.key());
+
+    Ok(())
+}
+
+fn send<'info>(
+    accounts: &TransferBandAssets<'info>,
+    band_seeds: &[&[u8]],
+    source: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    destination: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from: source.to_account_info(),
+                mint: mint.to_account_info(),
+                to: destination,
+                authority: accounts.band_pda.to_account_info(),
+            },
+            &[band_seeds],
+        ),
+        amount,
+        mint.decimals,
+    )
+}
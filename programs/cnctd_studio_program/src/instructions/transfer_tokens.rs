@@ -1,7 +1,7 @@
 use anchor_lang::prelude::{borsh::{BorshDeserialize, BorshSerialize}, *};
-use anchor_spl::{associated_token::{self, AssociatedToken}, token::{self, Token, TokenAccount, Transfer}, token_2022::{self, Token2022, TransferChecked as Transfer2022}};
+use anchor_spl::{associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken}, token::{self, Token, TokenAccount, Transfer}, token_2022::{self, Token2022, TransferChecked as Transfer2022}};
 
-use crate::{errors::CnctdStudioError, state::treasury::Treasury};
+use crate::{errors::CnctdStudioError, state::{governance::Governance, program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}}};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
 pub struct TransferTokensArgs {
@@ -11,7 +11,8 @@ pub struct TransferTokensArgs {
 
 #[derive(Accounts)]
 pub struct TransferTokens<'info> {
-    /// CHECK: Token mint of the specific token being transferred
+    /// CHECK: Token mint of the specific token being transferred, must be one the program handles
+    #[account(constraint = program_metadata.is_canonical_mint(&token_mint.key()) @ CnctdStudioError::InvalidMint)]
     pub token_mint: UncheckedAccount<'info>,
 
     /// Treasury PDA that holds the tokens
@@ -21,6 +22,27 @@ pub struct TransferTokens<'info> {
     )]
     pub treasury_pda: Account<'info, Treasury>,
 
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::AdminOps) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    /// Direct transfers are only allowed without a multisig threshold
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = !governance.requires_proposal() @ CnctdStudioError::MultisigApprovalRequired
+    )]
+    pub governance: Account<'info, Governance>,
+
     /// CHECK: The associated token account (ATA) for the Treasury PDA
     #[account(mut)]
     pub treasury_ata: UncheckedAccount<'info>,
@@ -33,9 +55,12 @@ pub struct TransferTokens<'info> {
     #[account(mut)]
     pub recipient_ata: UncheckedAccount<'info>,
 
-    /// CHECK: Admin of the treasury
-    #[account(mut)]
-    pub admin: UncheckedAccount<'info>,
+    /// Treasurer sending the tokens, pays for the recipient ATA if needed
+    #[account(
+        mut,
+        constraint = treasury_pda.has_role(&admin.key(), AdminRole::Treasurer) @ CnctdStudioError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
 
     /// Token Program
     pub token_program: Program<'info, Token>,
@@ -66,10 +91,16 @@ pub fn transfer_tokens(ctx: Context<TransferTokens>, data: TransferTokensArgs) -
         false => ctx.accounts.token_2022_program.to_account_info(),
     };
 
-    // Ensure the signer is authorized to send tokens
-    if !treasury_pda.is_admin(&admin.key()) {
-        return Err(CnctdStudioError::Unauthorized.into());
-    }
+    require_keys_eq!(
+        treasury_ata.key(),
+        get_associated_token_address_with_program_id(&treasury_pda.key(), &token_mint.key(), &token_program.key()),
+        CnctdStudioError::InvalidTreasuryATA
+    );
+    require_keys_eq!(
+        recipient_ata.key(),
+        get_associated_token_address_with_program_id(&recipient_pda.key(), &token_mint.key(), &token_program.key()),
+        CnctdStudioError::InvalidTokenAccountOwner
+    );
 
     msg!("Transferring {} tokens from Treasury to recipient", amount);
 
@@ -99,74 +130,78 @@ pub fn transfer_tokens(ctx: Context<TransferTokens>, data: TransferTokensArgs) -
         msg!("Recipient ATA Created: {:?}", recipient_ata.key());
     }
 
+    transfer_from_treasury(
+        token_program,
+        standard,
+        treasury_ata.to_account_info(),
+        token_mint.to_account_info(),
+        recipient_ata.to_account_info(),
+        treasury_pda.to_account_info(),
+        treasury_pda.bump,
+        amount,
+    )?;
+   
+    msg!("Transfer successful!");
+
+    Ok(())
+}
+
+/// Moves `amount` tokens out of a treasury-owned token account, signed by the treasury PDA.
+/// Shared with multisig proposal execution.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_treasury<'info>(
+    token_program: AccountInfo<'info>,
+    standard: bool,
+    treasury_ata: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    recipient_ata: AccountInfo<'info>,
+    treasury: AccountInfo<'info>,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let seeds: &[&[u8]] = &[b"treasury", &[treasury_bump]];
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    msg!("Transferring tokens...");
+    msg!("Treasury PDA: {:?}", treasury.key());
+    msg!("Treasury ATA: {:?}", treasury_ata.key());
+    msg!("Recipient ATA: {:?}", recipient_ata.key());
+    msg!("Token Mint: {:?}", token_mint.key());
+    msg!("Token Program: {:?}", token_program.key());
+
     match standard {
         true => {
             let cpi_accounts = Transfer {
-                from: treasury_ata.to_account_info(),
-                to: recipient_ata.to_account_info(),
-                authority: treasury_pda.to_account_info(),
+                from: treasury_ata,
+                to: recipient_ata,
+                authority: treasury,
             };
         
-            let bump = ctx.accounts.treasury_pda.bump;
-            let seeds: &[&[u8]] = &[b"treasury", &[bump]];
-            let signer_seeds: &[&[&[u8]]] = &[seeds];
-        
-            msg!("Transferring tokens...");
-            msg!("Treasury PDA: {:?}", ctx.accounts.treasury_pda.key());
-            msg!("Treasury ATA: {:?}", ctx.accounts.treasury_ata.key());
-            msg!("Treasury ATA Owner: {:?}", ctx.accounts.treasury_ata.owner);
-            msg!("Recipient ATA: {:?}", ctx.accounts.recipient_ata.key());
-            msg!("Recipient ATA Owner: {:?}", ctx.accounts.recipient_ata.owner);
-            msg!("Recipient ATA Data: {:?}", recipient_ata.to_account_info().data_len());
-            msg!("Token Mint: {:?}", ctx.accounts.token_mint.key());
-            msg!("Token Program: {:?}", token_program.key());
-        
-        
             let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
+                token_program,
                 cpi_accounts,
                 signer_seeds,
             );
         
             token::transfer(cpi_ctx, amount)?;
-        
         }
         false => {
             let cpi_accounts = Transfer2022 {
-                from: treasury_ata.to_account_info(),
-                mint: token_mint.to_account_info(),
-                to: recipient_ata.to_account_info(),
-                authority: treasury_pda.to_account_info(),
+                from: treasury_ata,
+                mint: token_mint,
+                to: recipient_ata,
+                authority: treasury,
             };
         
-            let bump = ctx.accounts.treasury_pda.bump;
-            let seeds: &[&[u8]] = &[b"treasury", &[bump]];
-            let signer_seeds: &[&[&[u8]]] = &[seeds];
-        
-            msg!("Transferring tokens...");
-            msg!("Treasury PDA: {:?}", ctx.accounts.treasury_pda.key());
-            msg!("Treasury ATA: {:?}", ctx.accounts.treasury_ata.key());
-            msg!("Treasury ATA Owner: {:?}", ctx.accounts.treasury_ata.owner);
-            msg!("Recipient ATA: {:?}", ctx.accounts.recipient_ata.key());
-            msg!("Recipient ATA Owner: {:?}", ctx.accounts.recipient_ata.owner);
-            msg!("Recipient ATA Data: {:?}", recipient_ata.to_account_info().data_len());
-            msg!("Token Mint: {:?}", ctx.accounts.token_mint.key());
-            msg!("Token Program: {:?}", token_program.key());
-        
-        
             let cpi_ctx = CpiContext::new_with_signer(
-                token_program.to_account_info(),
+                token_program,
                 cpi_accounts,
                 signer_seeds,
             );
         
             token_2022::transfer_checked(cpi_ctx, amount, 6)?;
-        
         }
     }
-    
-   
-    msg!("Transfer successful!");
 
     Ok(())
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CnctdStudioError,
+    state::{program_config::{PauseSubsystem, ProgramConfig}, treasury::{AdminRole, Treasury}},
+};
+
+/// Unlike pausing, resuming is reserved for super admins
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+}
+
+pub fn unpause(ctx: Context<Unpause>, subsystems: Vec<PauseSubsystem>) -> Result<()> {
+    require!(!subsystems.is_empty(), CnctdStudioError::InvalidInput);
+
+    let program_config = &mut ctx.accounts.program_config;
+    program_config.paused &= !ProgramConfig::mask_of(&subsystems);
+    program_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Unpaused {:?} by {}", subsystems, ctx.accounts.admin.key());
+
+    Ok(())
+}
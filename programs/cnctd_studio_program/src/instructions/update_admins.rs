@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::{errors::CnctdStudioError, state::treasury::Treasury};
+use crate::{constants::MAX_ADMINS, errors::CnctdStudioError, state::{governance::Governance, treasury::{AdminRole, Treasury}}};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
 pub enum AdminAction {
     Add,
     Remove,
@@ -13,27 +13,49 @@ pub struct UpdateAdmins<'info> {
         mut,
         seeds = [b"treasury"],
         bump = treasury.bump,
+        realloc = Treasury::space(),
+        realloc::payer = current_admin,
+        realloc::zero = false,
     )]
     pub treasury: Account<'info, Treasury>,
     
     /// The current admin performing the update (must be a signer)
-    #[account(constraint = treasury.is_admin(&current_admin.key()))]
+    #[account(
+        mut,
+        constraint = treasury.has_role(&current_admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
     pub current_admin: Signer<'info>,
     
     /// The admin being added or removed (doesn't need to sign)
     /// CHECK: We're just using this as a pubkey reference
     pub target_admin: UncheckedAccount<'info>,
+
+    /// Direct admin changes are only allowed without a multisig threshold
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = !governance.requires_proposal() @ CnctdStudioError::MultisigApprovalRequired,
+        constraint = !governance.requires_queued_admin_change() @ CnctdStudioError::AdminChangeQueued
+    )]
+    pub governance: Account<'info, Governance>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn update_admins(ctx: Context<UpdateAdmins>, action: AdminAction) -> Result<()> {
-    let treasury = &mut ctx.accounts.treasury;
     let target_key = ctx.accounts.target_admin.key();
-    let admin = &ctx.accounts.current_admin;
 
+    apply_admin_action(&mut ctx.accounts.treasury, &action, target_key)
+}
+
+/// Adds or removes `target_key` from the treasury admins. Shared with multisig proposal execution.
+pub fn apply_admin_action(treasury: &mut Treasury, action: &AdminAction, target_key: Pubkey) -> Result<()> {
     match action {
         AdminAction::Add => {
             // Only add if not already an admin
             if !treasury.is_admin(&target_key) {
+                require!(treasury.admins.len() < MAX_ADMINS, CnctdStudioError::TooManyAdmins);
+
                 treasury.admins.push(target_key);
                 msg!("Added new admin: {}", target_key);
             } else {
@@ -51,7 +73,15 @@ pub fn update_admins(ctx: Context<UpdateAdmins>, action: AdminAction) -> Result<
             // Find and remove the admin
             let position = treasury.admins.iter().position(|x| x == &target_key);
             if let Some(index) = position {
+                let roles_in_use = !treasury.roles.is_empty();
                 treasury.admins.remove(index);
+                treasury.roles.retain(|grant| grant.admin != target_key);
+
+                // Once roles are in use, someone must still be able to manage them
+                if roles_in_use && treasury.super_admin_count() == 0 {
+                    return Err(error!(CnctdStudioError::CannotRemoveLastSuperAdmin));
+                }
+
                 msg!("Removed admin: {}", target_key);
             } else {
                 msg!("Admin not found: {}", target_key);
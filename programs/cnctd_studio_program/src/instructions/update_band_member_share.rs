@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::CnctdStudioError, state::{band_pda::BandPDA, treasury::{AdminRole, Treasury}}};
+
+#[derive(Accounts)]
+#[instruction(band_id: String)]
+pub struct UpdateBandMemberShare<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        constraint = band_pda.has_address(&band_pda.key(), &band_id) @ CnctdStudioError::InvalidBandAccount,
+    )]
+    pub band_pda: Account<'info, BandPDA>,
+}
+
+pub fn update_band_member_share(ctx: Context<UpdateBandMemberShare>, _band_id: String, member: Pubkey, share_bps: u16) -> Result<()> {
+    let band = &mut ctx.accounts.band_pda;
+
+    let index = band.member_index(&member).ok_or(error!(CnctdStudioError::BandMemberNotFound))?;
+    band.members[index].share_bps = share_bps;
+    band.validate_members()?;
+
+    msg!("Member {} of band {} now has {} bps ({} bps allocated)",
+        member, band.key(), share_bps, band.total_share_bps());
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::emission_budget_args::EmissionBudgetArgs,
+    errors::CnctdStudioError,
+    state::{emission_budget::EmissionBudget, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct UpdateEmissionBudget<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"emission_budget", emission_budget.mint.as_ref()],
+        bump = emission_budget.bump
+    )]
+    pub emission_budget: Account<'info, EmissionBudget>,
+}
+
+pub fn update_emission_budget(ctx: Context<UpdateEmissionBudget>, args: EmissionBudgetArgs) -> Result<()> {
+    require!(args.epoch_duration > 0, CnctdStudioError::InvalidInput);
+
+    let now = Clock::get()?.unix_timestamp;
+    let emission_budget = &mut ctx.accounts.emission_budget;
+
+    // Close out the current epoch under the old duration before applying the new one
+    emission_budget.roll_epoch(now);
+
+    emission_budget.epoch_duration = args.epoch_duration;
+    emission_budget.epoch_budget = args.epoch_budget;
+    emission_budget.lifetime_cap = args.lifetime_cap;
+    emission_budget.scale_down = args.scale_down;
+    emission_budget.updated_at = now;
+
+    msg!("Emission budget for mint {} updated by {}: {:?}", emission_budget.mint, ctx.accounts.admin.key(), args);
+
+    Ok(())
+}
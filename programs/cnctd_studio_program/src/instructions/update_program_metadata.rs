@@ -1,27 +1,61 @@
 use anchor_lang::prelude::*;
 
-use crate::state::program_metadata::ProgramMetadata;
+use crate::{
+    arguments::program_metadata_args::UpdateProgramMetadataArgs,
+    constants::BASIS_POINTS,
+    errors::CnctdStudioError,
+    state::{program_metadata::ProgramMetadata, treasury::{AdminRole, Treasury}},
+};
 
 #[derive(Accounts)]
 pub struct UpdateMetadata<'info> {
+    /// Reallocs metadata written before the mints and treasury fee were recorded. The mints and fee recipient
+    /// themselves are changed through governance proposals.
     #[account(
         mut,
-        has_one = owner,  // Ensures only the owner can update
         seeds = [b"program_metadata"],
-        bump
+        bump = metadata.bump,
+        realloc = ProgramMetadata::space(),
+        realloc::payer = owner,
+        realloc::zero = false,
     )]
     pub metadata: Account<'info, ProgramMetadata>,
 
-    #[account(signer)]
-    pub owner: AccountInfo<'info>,
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Super admin making the update
+    #[account(
+        mut,
+        constraint = treasury.has_role(&owner.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized
+    )]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
-pub fn update_metadata(ctx: Context<UpdateMetadata>, new_owner: Pubkey, new_version: u8) -> Result<()> {
+pub fn update_metadata(ctx: Context<UpdateMetadata>, args: UpdateProgramMetadataArgs) -> Result<()> {
     let metadata = &mut ctx.accounts.metadata;
-    
-    metadata.owner = new_owner;
-    metadata.version = new_version;
-    
-    msg!("Program metadata updated. New Owner: {}, New Version: {}", new_owner, new_version);
+
+    if let Some(new_owner) = args.new_owner {
+        metadata.owner = new_owner;
+    }
+    if let Some(version) = args.version {
+        metadata.version = version;
+    }
+    if let Some(treasury_fee_bps) = args.treasury_fee_bps {
+        require!(treasury_fee_bps as u64 <= BASIS_POINTS, CnctdStudioError::InvalidTreasuryFee);
+        metadata.treasury_fee_bps = treasury_fee_bps;
+    }
+    metadata.treasury_pda = ctx.accounts.treasury.key();
+    metadata.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Program metadata updated. Owner: {}, Version: {}", metadata.owner, metadata.version);
+    msg!("USDC: {}, CNCTD: {}, MUSIC: {}, fee recipient: {}, treasury fee: {} bps",
+        metadata.usdc_mint, metadata.cnctd_mint, metadata.music_mint, metadata.fee_recipient, metadata.treasury_fee_bps);
+
     Ok(())
 }
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::reimbursement_config_args::ReimbursementConfigArgs,
+    errors::CnctdStudioError,
+    state::{reimbursement::ReimbursementConfig, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct UpdateReimbursementConfig<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"reimbursement_config"],
+        bump = reimbursement_config.bump
+    )]
+    pub reimbursement_config: Account<'info, ReimbursementConfig>,
+}
+
+pub fn update_reimbursement_config(ctx: Context<UpdateReimbursementConfig>, args: ReimbursementConfigArgs) -> Result<()> {
+    let reimbursement_config = &mut ctx.accounts.reimbursement_config;
+
+    reimbursement_config.max_per_tx = args.max_per_tx;
+    reimbursement_config.max_per_day = args.max_per_day;
+    reimbursement_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Reimbursement config updated by {}: {:?}", ctx.accounts.admin.key(), args);
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    arguments::reward_config_args::RewardConfigArgs,
+    errors::CnctdStudioError,
+    state::{reward_config::RewardConfig, treasury::{AdminRole, Treasury}},
+};
+
+#[derive(Accounts)]
+pub struct UpdateRewardConfig<'info> {
+    #[account(constraint = treasury.has_role(&admin.key(), AdminRole::SuperAdmin) @ CnctdStudioError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_config"],
+        bump = reward_config.bump
+    )]
+    pub reward_config: Account<'info, RewardConfig>,
+}
+
+pub fn update_reward_config(ctx: Context<UpdateRewardConfig>, args: RewardConfigArgs) -> Result<()> {
+    let reward_config = &mut ctx.accounts.reward_config;
+
+    reward_config.enabled = args.enabled;
+    reward_config.buyer_rate_bps = args.buyer_rate_bps;
+    reward_config.artist_rate_bps = args.artist_rate_bps;
+    reward_config.buyer_cap_per_tx = args.buyer_cap_per_tx;
+    reward_config.artist_cap_per_tx = args.artist_cap_per_tx;
+    reward_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Reward config updated by {}: {:?}", ctx.accounts.admin.key(), args);
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    arguments::withdraw_args::WithdrawArgs,
+    errors::CnctdStudioError,
+    state::{program_config::{PauseSubsystem, ProgramConfig}, program_metadata::ProgramMetadata, user_pda::UserPDA},
+};
+
+/// Moves USDC, CNCTD or MUSIC out of a user's program-derived ATA to any token account of that
+/// mint, signed by their bound wallet. Custody accounts only decide where rewards and close-out
+/// sweeps land; they don't restrict the user's own withdrawals. Withdrawals can be paused on
+/// their own, so a compromised binding can be contained without halting purchases.
+#[derive(Accounts)]
+#[instruction(args: WithdrawArgs)]
+pub struct Withdraw<'info> {
+    pub auth: Signer<'info>,
+
+    #[account(
+        constraint = user_pda.has_address(&user_pda.key(), &args.user_id) @ CnctdStudioError::InvalidUserAccount,
+        constraint = user_pda.auth == Some(auth.key()) @ CnctdStudioError::Unauthorized,
+    )]
+    pub user_pda: Account<'info, UserPDA>,
+
+    #[account(
+        seeds = [b"program_metadata"],
+        bump = program_metadata.bump,
+    )]
+    pub program_metadata: Account<'info, ProgramMetadata>,
+
+    #[account(
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = !program_config.is_paused(PauseSubsystem::Withdrawals) @ CnctdStudioError::ProgramPaused
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    #[account(
+        constraint = program_metadata.is_canonical_mint(&mint.key()) @ CnctdStudioError::InvalidMint,
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&user_pda.key(), &mint.key(), &token_program.key()) @ CnctdStudioError::InvalidTokenAccountOwner
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == mint.key() @ CnctdStudioError::InvalidMint,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw(ctx: Context<Withdraw>, args: WithdrawArgs) -> Result<()> {
+    require!(args.amount > 0, CnctdStudioError::InvalidInput);
+    require!(ctx.accounts.source.amount >= args.amount, CnctdStudioError::InsufficientFunds);
+
+    msg!("Withdrawing {} of mint {} from {} to {}",
+        args.amount, ctx.accounts.mint.key(), ctx.accounts.source.key(), ctx.accounts.destination.key());
+
+    let user_seed = ctx.accounts.user_pda.signer_seed_id(&ctx.accounts.user_pda.key(), &args.user_id)?;
+    let user_seeds: &[&[u8]] = &[b"user", user_seed.as_ref(), &[ctx.accounts.user_pda.bump]];
+
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.user_pda.to_account_info(),
+            },
+            &[user_seeds],
+        ),
+        args.amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    msg!("Withdrawal complete");
+    Ok(())
+}
@@ -6,22 +6,35 @@ pub mod errors;
 pub mod constants;
 pub mod arguments;
 pub mod utils;
+pub mod math;
+pub mod rewards;
+pub mod pda;
 
 use crate::arguments::{
     metadata::Metadata,
     initialize_token_mint_args::InitializeTokenMintArgs,
     initialize_user_args::InitializeUserArgs,
     initialize_band_args::InitializeBandArgs,
+    reward_config_args::RewardConfigArgs,
+    emission_budget_args::EmissionBudgetArgs,
+    reimbursement_config_args::ReimbursementConfigArgs,
+    program_metadata_args::UpdateProgramMetadataArgs,
+    withdraw_args::WithdrawArgs,
     release::{
         OpenEscrowArgs,
         FulfillReleaseArgs,
         CompleteReleaseArgs,
         PurchaseAccessArgs,
         CloseReleaseAccessArgs,
+        RefundEscrowArgs,
+        ReclaimExpiredEscrowArgs,
+        CreateReleaseArgs,
+        UpdateReleaseArgs,
     },
 };
 
 use instructions::*;
+use state::{governance::ProposalAction, program_config::PauseSubsystem, treasury::AdminRole};
 
 declare_id!("CSPd6eauKNBXfrQnKmqrHKEjt6xtW7mgzmfV2XPfiy5i");
 
@@ -30,9 +43,13 @@ pub mod cnctd_solana_program {
 
     use super::*;
 
-    // pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-    //     instructions::initialize::initialize(ctx)
-    // }
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+        instructions::initialize::initialize(ctx)
+    }
+
+    pub fn update_program_metadata(ctx: Context<UpdateMetadata>, args: UpdateProgramMetadataArgs) -> Result<()> {
+        instructions::update_program_metadata::update_metadata(ctx, args)
+    }
 
     // pub fn close_treasury(ctx: Context<CloseTreasury>) -> Result<()> {
     //     instructions::close_treasury::close_treasury(ctx)
@@ -69,14 +86,136 @@ pub mod cnctd_solana_program {
         instructions::initialize_band::initialize_band(ctx, data)
     }
 
+    pub fn add_band_member(ctx: Context<AddBandMember>, band_id: String, share_bps: u16) -> Result<()> {
+        instructions::add_band_member::add_band_member(ctx, band_id, share_bps)
+    }
+
+    pub fn remove_band_member(ctx: Context<RemoveBandMember>, band_id: String, member: Pubkey) -> Result<()> {
+        instructions::remove_band_member::remove_band_member(ctx, band_id, member)
+    }
+
+    pub fn update_band_member_share(ctx: Context<UpdateBandMemberShare>, band_id: String, member: Pubkey, share_bps: u16) -> Result<()> {
+        instructions::update_band_member_share::update_band_member_share(ctx, band_id, member, share_bps)
+    }
+
+    pub fn distribute_band_revenue<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeBandRevenue<'info>>,
+        band_id: String
+    ) -> Result<()> {
+        instructions::distribute_band_revenue::distribute_band_revenue(ctx, band_id)
+    }
+
+    pub fn transfer_band_assets<'info>(
+        ctx: Context<'_, '_, '_, 'info, TransferBandAssets<'info>>,
+        band_id: String
+    ) -> Result<()> {
+        instructions::transfer_band_assets::transfer_band_assets(ctx, band_id)
+    }
+
+    pub fn close_band(ctx: Context<CloseBand>, band_id: String) -> Result<()> {
+        instructions::close_band::close_band(ctx, band_id)
+    }
+
     pub fn close_user_account(ctx: Context<CloseUserAccount>, user_id: String) -> Result<()> {
         instructions::close_user_account::close_user_account(ctx, user_id)
     }
 
+    pub fn bind_user_auth(ctx: Context<BindUserAuth>, user_id: String, auth: Option<Pubkey>) -> Result<()> {
+        instructions::bind_user_auth::bind_user_auth(ctx, user_id, auth)
+    }
+
+    pub fn set_custody_accounts(ctx: Context<SetCustodyAccounts>, user_id: String) -> Result<()> {
+        instructions::set_custody_accounts::set_custody_accounts(ctx, user_id)
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, args: WithdrawArgs) -> Result<()> {
+        instructions::withdraw::withdraw(ctx, args)
+    }
+
     pub fn update_admins(ctx: Context<UpdateAdmins>, action: AdminAction) -> Result<()> {
         instructions::update_admins::update_admins(ctx, action)
     }
 
+    pub fn initialize_reward_config(ctx: Context<InitializeRewardConfig>, args: RewardConfigArgs) -> Result<()> {
+        instructions::initialize_reward_config::initialize_reward_config(ctx, args)
+    }
+
+    pub fn update_reward_config(ctx: Context<UpdateRewardConfig>, args: RewardConfigArgs) -> Result<()> {
+        instructions::update_reward_config::update_reward_config(ctx, args)
+    }
+
+    pub fn initialize_emission_budget(ctx: Context<InitializeEmissionBudget>, args: EmissionBudgetArgs) -> Result<()> {
+        instructions::initialize_emission_budget::initialize_emission_budget(ctx, args)
+    }
+
+    pub fn update_emission_budget(ctx: Context<UpdateEmissionBudget>, args: EmissionBudgetArgs) -> Result<()> {
+        instructions::update_emission_budget::update_emission_budget(ctx, args)
+    }
+
+    pub fn get_emission_budget(ctx: Context<GetEmissionBudget>) -> Result<()> {
+        instructions::get_emission_budget::get_emission_budget(ctx)
+    }
+
+    pub fn initialize_reimbursement_config(ctx: Context<InitializeReimbursementConfig>, args: ReimbursementConfigArgs) -> Result<()> {
+        instructions::initialize_reimbursement_config::initialize_reimbursement_config(ctx, args)
+    }
+
+    pub fn update_reimbursement_config(ctx: Context<UpdateReimbursementConfig>, args: ReimbursementConfigArgs) -> Result<()> {
+        instructions::update_reimbursement_config::update_reimbursement_config(ctx, args)
+    }
+
+    pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>, guardian: Pubkey) -> Result<()> {
+        instructions::initialize_program_config::initialize_program_config(ctx, guardian)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian::set_guardian(ctx, guardian)
+    }
+
+    pub fn pause(ctx: Context<Pause>, subsystems: Vec<PauseSubsystem>) -> Result<()> {
+        instructions::pause::pause(ctx, subsystems)
+    }
+
+    pub fn unpause(ctx: Context<Unpause>, subsystems: Vec<PauseSubsystem>) -> Result<()> {
+        instructions::unpause::unpause(ctx, subsystems)
+    }
+
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account(ctx)
+    }
+
+    pub fn create_release(ctx: Context<CreateRelease>, args: CreateReleaseArgs) -> Result<()> {
+        instructions::release::create_release(ctx, args)
+    }
+
+    pub fn update_release(ctx: Context<UpdateRelease>, args: UpdateReleaseArgs) -> Result<()> {
+        instructions::release::update_release(ctx, args)
+    }
+
+    pub fn set_admin_roles(ctx: Context<SetAdminRoles>, roles: Vec<AdminRole>) -> Result<()> {
+        instructions::set_admin_roles::set_admin_roles(ctx, roles)
+    }
+
+    pub fn initialize_governance(ctx: Context<InitializeGovernance>, approval_threshold: u8, admin_change_delay: i64) -> Result<()> {
+        instructions::initialize_governance::initialize_governance(ctx, approval_threshold, admin_change_delay)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::create_proposal::create_proposal(ctx, action)
+    }
+
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::approve_proposal(ctx)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::execute_proposal(ctx)
+    }
+
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> Result<()> {
+        instructions::cancel_proposal::cancel_proposal(ctx)
+    }
+
     pub fn open_release_escrow(ctx: Context<OpenEscrow>, args: OpenEscrowArgs) -> Result<()> {
         instructions::release::open_escrow(ctx, args)
     }
@@ -113,6 +252,14 @@ pub mod cnctd_solana_program {
         instructions::release::close_release_access(ctx, args)
     }
 
+    pub fn refund_release_escrow(ctx: Context<RefundEscrow>, args: RefundEscrowArgs) -> Result<()> {
+        instructions::release::refund_escrow(ctx, args)
+    }
+
+    pub fn reclaim_expired_escrow(ctx: Context<ReclaimExpiredEscrow>, args: ReclaimExpiredEscrowArgs) -> Result<()> {
+        instructions::release::reclaim_expired_escrow(ctx, args)
+    }
+
     // pub fn close_release_escrow(ctx: Context<CloseEscrow>, args: CloseEscrowArgs) -> Result<()> {
     //     instructions::release_nft::close_escrow(ctx, args)
     // }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CnctdStudioError;
+
+/// Divides `total` between `weights` proportionally using the largest remainder method.
+///
+/// Each share is rounded down first, then the leftover lamports (the dust) are handed out one
+/// at a time to the largest fractional remainders, lowest index first on ties. The result always
+/// sums to exactly `total`, unless every weight is zero in which case every share is zero.
+pub fn apportion(total: u64, weights: &[u64]) -> Result<Vec<u64>> {
+    let weight_total = weights
+        .iter()
+        .try_fold(0u128, |acc, weight| acc.checked_add(*weight as u128))
+        .ok_or(CnctdStudioError::MathOverflow)?;
+
+    if weight_total == 0 {
+        return Ok(vec![0; weights.len()]);
+    }
+
+    let mut shares = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    let mut distributed: u128 = 0;
+
+    for (i, weight) in weights.iter().enumerate() {
+        let product = (total as u128)
+            .checked_mul(*weight as u128)
+            .ok_or(CnctdStudioError::MathOverflow)?;
+        let share = product / weight_total;
+
+        shares.push(share as u64);
+        remainders.push((product % weight_total, i));
+        distributed += share;
+    }
+
+    let dust = (total as u128)
+        .checked_sub(distributed)
+        .ok_or(CnctdStudioError::MathOverflow)? as usize;
+
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    for (_, i) in remainders.into_iter().take(dust) {
+        shares[i] += 1;
+    }
+
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares_sum_to_total() {
+        let shares = apportion(1_000_003, &[5_000, 3_000, 2_000]).unwrap();
+        assert_eq!(shares.iter().sum::<u64>(), 1_000_003);
+        assert_eq!(shares, vec![500_001, 300_001, 200_001]);
+    }
+
+    #[test]
+    fn dust_ties_go_to_lowest_index() {
+        assert_eq!(apportion(10, &[1, 1, 1]).unwrap(), vec![4, 3, 3]);
+        assert_eq!(apportion(2, &[1, 1, 1]).unwrap(), vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn dust_goes_to_largest_remainder() {
+        // 7 * 1/6 = 1.17, 7 * 2/6 = 2.33, 7 * 3/6 = 3.5
+        assert_eq!(apportion(7, &[1, 2, 3]).unwrap(), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn all_zero_weights_give_zero_shares() {
+        assert_eq!(apportion(100, &[0, 0, 0]).unwrap(), vec![0, 0, 0]);
+        assert_eq!(apportion(100, &[]).unwrap(), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn max_total_does_not_overflow() {
+        for weights in [vec![u64::MAX, u64::MAX, 1], vec![3, 7]] {
+            let shares = apportion(u64::MAX, &weights).unwrap();
+            let sum: u128 = shares.iter().map(|share| *share as u128).sum();
+            assert_eq!(sum, u64::MAX as u128);
+        }
+    }
+
+    #[test]
+    fn single_recipient_takes_everything() {
+        assert_eq!(apportion(12_345, &[1]).unwrap(), vec![12_345]);
+        assert_eq!(apportion(0, &[10_000]).unwrap(), vec![0]);
+    }
+}
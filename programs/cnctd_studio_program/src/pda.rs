@@ -0,0 +1,98 @@
+//! Addresses of every PDA the program owns. Clients should derive accounts through these
+//! helpers instead of re-implementing the seed logic.
+use anchor_lang::prelude::*;
+
+use crate::utils::UuidFormatting;
+
+fn find(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &crate::ID)
+}
+
+pub fn treasury() -> (Pubkey, u8) {
+    find(&[b"treasury"])
+}
+
+pub fn program_metadata() -> (Pubkey, u8) {
+    find(&[b"program_metadata"])
+}
+
+pub fn program_config() -> (Pubkey, u8) {
+    find(&[b"program_config"])
+}
+
+pub fn governance() -> (Pubkey, u8) {
+    find(&[b"governance"])
+}
+
+pub fn proposal(id: u64) -> (Pubkey, u8) {
+    find(&[b"proposal", &id.to_le_bytes()])
+}
+
+pub fn reward_config() -> (Pubkey, u8) {
+    find(&[b"reward_config"])
+}
+
+pub fn emission_budget(mint: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"emission_budget", mint.as_ref()])
+}
+
+pub fn reimbursement_config() -> (Pubkey, u8) {
+    find(&[b"reimbursement_config"])
+}
+
+pub fn admin_usage(admin: &Pubkey) -> (Pubkey, u8) {
+    find(&[b"admin_usage", admin.as_ref()])
+}
+
+pub fn release(release_id: &str) -> (Pubkey, u8) {
+    find(&[b"release", release_id.to_solana_seed_format().as_bytes()])
+}
+
+pub fn release_escrow(release_id: &str, buyer_id: &str) -> (Pubkey, u8) {
+    find(&[b"release_escrow", &release_id.to_uuid_bytes(), &buyer_id.to_uuid_bytes()])
+}
+
+/// Escrows opened before 16-byte UUID seeds, derived from 7-byte id prefixes
+pub fn legacy_release_escrow(release_id: &str, buyer_id: &str) -> (Pubkey, u8) {
+    find(&[b"release_escrow", &release_id.to_7_byte_seed(), &buyer_id.to_7_byte_seed()])
+}
+
+pub fn release_access(release_id: &str, buyer_id: &str) -> (Pubkey, u8) {
+    find(&[b"release_access", &release_id.to_uuid_bytes(), &buyer_id.to_uuid_bytes()])
+}
+
+/// Access records created before 16-byte UUID seeds, derived from 7-byte id prefixes
+pub fn legacy_release_access(release_id: &str, buyer_id: &str) -> (Pubkey, u8) {
+    find(&[b"release_access", &release_id.to_7_byte_seed(), &buyer_id.to_7_byte_seed()])
+}
+
+pub fn user(user_id: &str) -> (Pubkey, u8) {
+    find(&[b"user", &user_id.to_uuid_bytes()])
+}
+
+/// Users created before 16-byte UUID seeds, derived from the id string without hyphens, the
+/// only form short enough to have been used as a seed. `None` if the id is still too long.
+pub fn legacy_user(user_id: &str) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(&[b"user", user_id.to_solana_seed_format().as_bytes()], &crate::ID)
+}
+
+pub fn band(band_id: &str) -> (Pubkey, u8) {
+    find(&[b"band", &band_id.to_uuid_bytes()])
+}
+
+/// Bands created before 16-byte UUID seeds, derived from the id string without hyphens, the
+/// only form short enough to have been used as a seed. `None` if the id is still too long.
+pub fn legacy_band(band_id: &str) -> Option<(Pubkey, u8)> {
+    Pubkey::try_find_program_address(&[b"band", band_id.to_solana_seed_format().as_bytes()], &crate::ID)
+}
+
+/// Seed component for an id-keyed PDA (`user` or `band`) at `key` with the stored `bump`,
+/// under whichever scheme it was created with. Legacy seeds use the same hyphen-less form as
+/// `legacy_user` and `legacy_band`, so either spelling of the id resolves.
+pub fn id_seed(prefix: &[u8], id: &str, key: &Pubkey, bump: u8) -> Option<Vec<u8>> {
+    [id.to_uuid_bytes().to_vec(), id.to_solana_seed_format().into_bytes()]
+        .into_iter()
+        .find(|seed| {
+            Pubkey::create_program_address(&[prefix, seed, &[bump]], &crate::ID).as_ref() == Ok(key)
+        })
+}
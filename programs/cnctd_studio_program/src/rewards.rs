@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{mint_to, MintTo};
+
+use crate::{arguments::release::PaymentSplit, errors::CnctdStudioError, math::apportion, state::user_pda::UserPDA};
+
+/// Splits `total_reward` between artists in proportion to their USDC payment, so the
+/// amounts always add up to exactly `total_reward`. Artists are rewarded evenly when
+/// none of them received a payment.
+pub fn artist_rewards(total_reward: u64, payment_splits: &[PaymentSplit]) -> Result<Vec<u64>> {
+    let mut weights: Vec<u64> = payment_splits.iter().map(|split| split.amount).collect();
+
+    if weights.iter().all(|weight| *weight == 0) {
+        weights = vec![1; weights.len()];
+    }
+
+    apportion(total_reward, &weights)
+}
+
+/// Checks the token account a split's CNCTD reward is minted to. That is the split's CNCTD ATA,
+/// unless the recipient is a user with a CNCTD custody account: passing their UserPDA from
+/// `user_accounts` lets the reward go to the custody account instead.
+pub fn verify_cnctd_destination<'a, 'info: 'a>(
+    destination: &AccountInfo<'info>,
+    split: &PaymentSplit,
+    cnctd_mint: &Pubkey,
+    user_accounts: &mut impl Iterator<Item = &'a AccountInfo<'info>>,
+) -> Result<()> {
+    if destination.key() == split.recipient_cnctd_ata {
+        return Ok(());
+    }
+
+    let user_info = user_accounts.next().ok_or(error!(CnctdStudioError::InvalidPaymentReceiver))?;
+    require_keys_eq!(*user_info.owner, crate::ID, CnctdStudioError::InvalidUserAccount);
+    let user = UserPDA::try_deserialize(&mut &user_info.data.borrow()[..])?;
+
+    // The split's CNCTD ATA is derived from its recipient, which ties the UserPDA to this split
+    require_keys_eq!(
+        UserPDA::reward_ata(user_info.key, cnctd_mint),
+        split.recipient_cnctd_ata,
+        CnctdStudioError::InvalidPaymentReceiver
+    );
+    require!(
+        user.accepts_cnctd_reward(user_info.key, cnctd_mint, destination.key),
+        CnctdStudioError::InvalidPaymentReceiver
+    );
+
+    Ok(())
+}
+
+/// Mints a Token-2022 reward token with the treasury PDA as mint authority
+pub fn mint_reward<'info>(
+    token_2022_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    treasury_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let treasury_seeds: &[&[u8]] = &[b"treasury", &[treasury_bump]];
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_2022_program.clone(),
+            MintTo {
+                mint: mint.clone(),
+                to: destination.clone(),
+                authority: treasury.clone(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+    )
+}
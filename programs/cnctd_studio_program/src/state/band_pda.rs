@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::{BASIS_POINTS, MAX_BAND_MEMBERS}, errors::CnctdStudioError, math::apportion, pda, state::versioning::Versioned};
+
 #[account]
+#[derive(InitSpace)]
 pub struct BandPDA {
     pub admin: Pubkey,        // Always your program's treasury PDA
     pub usdc_ata: Pubkey,      // Program-derived ATA (default)
@@ -8,4 +11,79 @@ pub struct BandPDA {
     pub fees_waived: u64, // Fees that have been waived
     pub waived_count: u64, // Number of waived transactions
     pub bump: u8, // PDA bump seed
-}
\ No newline at end of file
+    pub version: u8, // Layout version
+    #[max_len(MAX_BAND_MEMBERS)]
+    pub members: Vec<BandMember>, // Members and their share of band revenue
+    pub release_refs: u32, // Releases with a split share paying this band
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct BandMember {
+    pub user_pda: Pubkey, // Member's UserPDA
+    pub share_bps: u16,   // Share of band revenue, in basis points
+}
+
+impl BandPDA {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Seed component for signing as the band at `key`. Bands created before 16-byte UUID seeds
+    /// are derived from the raw id string.
+    pub fn signer_seed_id(&self, key: &Pubkey, band_id: &str) -> Result<Vec<u8>> {
+        pda::id_seed(b"band", band_id, key, self.bump).ok_or(error!(CnctdStudioError::InvalidBandAccount))
+    }
+
+    pub fn member_index(&self, user_pda: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|member| member.user_pda == *user_pda)
+    }
+
+    pub fn total_share_bps(&self) -> u64 {
+        self.members.iter().map(|member| member.share_bps as u64).sum()
+    }
+
+    /// Splits `amount` by member share. Whatever the shares leave unallocated is returned
+    /// separately, so a band without members hands everything back.
+    pub fn member_amounts(&self, amount: u64) -> Result<(Vec<u64>, u64)> {
+        let mut weights: Vec<u64> = self.members.iter().map(|member| member.share_bps as u64).collect();
+        weights.push(BASIS_POINTS.saturating_sub(self.total_share_bps()));
+
+        let mut amounts = apportion(amount, &weights)?;
+        let unallocated = amounts.pop().unwrap_or(amount);
+        Ok((amounts, unallocated))
+    }
+
+    /// Shares may add up to less than 100% while membership is being edited, never more
+    pub fn validate_members(&self) -> Result<()> {
+        require!(self.members.len() <= MAX_BAND_MEMBERS, CnctdStudioError::TooManyBandMembers);
+        require!(self.total_share_bps() <= BASIS_POINTS, CnctdStudioError::InvalidSplitTotal);
+        Ok(())
+    }
+
+    /// Whether `key` is the PDA of `band_id` under either seed scheme
+    pub fn has_address(&self, key: &Pubkey, band_id: &str) -> bool {
+        pda::id_seed(b"band", band_id, key, self.bump).is_some()
+    }
+}
+
+impl Versioned for BandPDA {
+    const VERSION: u8 = 1;
+
+    fn current_space() -> usize {
+        BandPDA::space()
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _key: &Pubkey) -> Result<()> {
+        // Bands from before versioning have no members, so everything they receive stays
+        // unallocated until members are added. Releases whose splits were set before then aren't
+        // counted in release_refs, so update their splits before closing such a band.
+        self.members = Vec::new();
+        self.release_refs = 0;
+        self.version = Self::VERSION;
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CnctdStudioError;
+
+#[account]
+#[derive(InitSpace)]
+pub struct EmissionBudget {
+    pub mint: Pubkey,          // Reward mint this budget governs
+    pub epoch_duration: i64,   // Length of an epoch in seconds
+    pub epoch_budget: u64,     // Max minted per epoch (0 = unlimited)
+    pub lifetime_cap: u64,     // Max minted over the budget's lifetime (0 = unlimited)
+    pub scale_down: bool,      // Mint whatever is left instead of rejecting once a budget is exhausted
+    pub epoch_start: i64,      // Start of the current epoch
+    pub epoch_minted: u64,     // Minted in the current epoch
+    pub lifetime_minted: u64,  // Minted since the budget was created
+    pub updated_at: i64,       // Last modification timestamp
+    pub bump: u8,              // PDA bump seed
+}
+
+impl EmissionBudget {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Starts a new epoch, aligned to the previous one, once the current epoch has elapsed
+    pub fn roll_epoch(&mut self, now: i64) {
+        if self.epoch_duration > 0 && now >= self.epoch_start.saturating_add(self.epoch_duration) {
+            let elapsed = now - self.epoch_start;
+            self.epoch_start = now - (elapsed % self.epoch_duration);
+            self.epoch_minted = 0;
+        }
+    }
+
+    /// Amount that can still be minted right now
+    pub fn remaining(&self) -> u64 {
+        let epoch_remaining = if self.epoch_budget > 0 {
+            self.epoch_budget.saturating_sub(self.epoch_minted)
+        } else {
+            u64::MAX
+        };
+        let lifetime_remaining = if self.lifetime_cap > 0 {
+            self.lifetime_cap.saturating_sub(self.lifetime_minted)
+        } else {
+            u64::MAX
+        };
+
+        epoch_remaining.min(lifetime_remaining)
+    }
+
+    /// Records `requested` tokens against the budget and returns how many may actually be minted.
+    /// Fails with `EmissionBudgetExceeded` unless the budget is configured to scale rewards down.
+    pub fn consume(&mut self, requested: u64, now: i64) -> Result<u64> {
+        self.roll_epoch(now);
+
+        let allowed = requested.min(self.remaining());
+        if allowed < requested {
+            msg!("Emission budget for {} allows {} of {} requested", self.mint, allowed, requested);
+            require!(self.scale_down, CnctdStudioError::EmissionBudgetExceeded);
+        }
+
+        self.epoch_minted = self.epoch_minted.checked_add(allowed).ok_or(CnctdStudioError::MathOverflow)?;
+        self.lifetime_minted = self.lifetime_minted.checked_add(allowed).ok_or(CnctdStudioError::MathOverflow)?;
+
+        Ok(allowed)
+    }
+}
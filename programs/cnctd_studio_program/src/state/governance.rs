@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_ADMINS, MAX_ADMIN_CHANGE_DELAY},
+    errors::CnctdStudioError,
+    instructions::update_admins::AdminAction,
+    state::{program_metadata::CanonicalMint, treasury::{AdminRole, Treasury}},
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    pub approval_threshold: u8, // Admin approvals required before a proposal can execute
+    pub proposal_count: u64,    // Id assigned to the next proposal
+    pub updated_at: i64,        // Last modification timestamp
+    pub bump: u8,               // PDA bump seed
+    pub admin_change_delay: i64, // Seconds an admin change stays queued before it can execute
+}
+
+impl Governance {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Admin changes must be queued once a delay is configured
+    pub fn requires_queued_admin_change(&self) -> bool {
+        self.admin_change_delay > 0
+    }
+
+    /// Sensitive treasury operations must go through a proposal once more than one approval is needed
+    pub fn requires_proposal(&self) -> bool {
+        self.approval_threshold > 1
+    }
+
+    /// Threshold must be reachable by the current admin set
+    pub fn validate_threshold(threshold: u8, treasury: &Treasury) -> Result<()> {
+        require!(
+            threshold >= 1 && threshold as usize <= treasury.admins.len(),
+            CnctdStudioError::InvalidThreshold
+        );
+        Ok(())
+    }
+
+    pub fn validate_admin_change_delay(delay: i64) -> Result<()> {
+        require!(
+            (0..=MAX_ADMIN_CHANGE_DELAY).contains(&delay),
+            CnctdStudioError::InvalidTimelockDelay
+        );
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub enum ProposalAction {
+    UpdateAdmin { action: AdminAction, target: Pubkey },
+    TransferTokens { mint: Pubkey, recipient_ata: Pubkey, amount: u64, standard: bool },
+    MintTokens { mint: Pubkey, destination: Pubkey, amount: u64, standard: bool },
+    SetThreshold { threshold: u8 },
+    SetAdminChangeDelay { delay: i64 },
+    SetAdminRoles { target: Pubkey, roles: u8 }, // Bitmask of AdminRole::mask()
+    SetCanonicalMint { kind: CanonicalMint, mint: Pubkey },
+    SetFeeRecipient { fee_recipient: Pubkey },
+    BindUserAuth { user_pda: Pubkey, auth: Option<Pubkey> },
+}
+
+impl ProposalAction {
+    /// Role an admin needs to propose or approve this action
+    pub fn required_role(&self) -> AdminRole {
+        match self {
+            ProposalAction::UpdateAdmin { .. }
+            | ProposalAction::SetThreshold { .. }
+            | ProposalAction::SetAdminChangeDelay { .. }
+            | ProposalAction::SetAdminRoles { .. }
+            | ProposalAction::SetCanonicalMint { .. }
+            | ProposalAction::SetFeeRecipient { .. }
+            | ProposalAction::BindUserAuth { .. } => AdminRole::SuperAdmin,
+            ProposalAction::TransferTokens { .. } => AdminRole::Treasurer,
+            ProposalAction::MintTokens { .. } => AdminRole::Minter,
+        }
+    }
+
+    /// Changes to who controls the treasury wait out the admin change delay
+    pub fn is_timelocked(&self) -> bool {
+        self.required_role() == AdminRole::SuperAdmin
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,                // Sequential proposal id, part of the PDA seeds
+    pub proposer: Pubkey,       // Admin who created the proposal
+    pub action: ProposalAction, // Operation to run once approved
+    #[max_len(MAX_ADMINS)]
+    pub approvals: Vec<Pubkey>, // Admins who approved, including the proposer
+    pub created_at: i64,        // Creation timestamp
+    pub executed: bool,         // Set once the action has run
+    pub bump: u8,               // PDA bump seed
+    pub eta: i64,               // Earliest execution timestamp
+    pub cancelled: bool,        // Set when an admin cancels the proposal
+}
+
+impl Proposal {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Approvals from signers that still hold the role the action requires
+    pub fn valid_approvals(&self, treasury: &Treasury) -> usize {
+        let role = self.action.required_role();
+        self.approvals.iter().filter(|approver| treasury.has_role(approver, role)).count()
+    }
+}
@@ -5,4 +5,11 @@ pub mod user_pda;
 pub mod band_pda;
 pub mod mint_album;
 pub mod release_escrow;
-pub mod release_access;
\ No newline at end of file
+pub mod release;
+pub mod release_access;
+pub mod reward_config;
+pub mod emission_budget;
+pub mod governance;
+pub mod reimbursement;
+pub mod program_config;
+pub mod versioning;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PauseSubsystem {
+    Purchases,   // Release creation, escrows, access purchases, fulfillment and completion
+    Rewards,     // MUSIC and CNCTD purchase rewards, access purchases continue without them and escrows wait
+    AdminOps,    // Treasury mints and transfers, band asset recovery and migrations
+    Withdrawals, // Funds leaving user and band accounts: withdrawals, sweeps and band payouts
+}
+
+impl PauseSubsystem {
+    pub fn mask(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramConfig {
+    pub guardian: Pubkey,  // Key allowed to pause without being an admin
+    pub paused: u8,        // Bitmask of PauseSubsystem::mask()
+    pub updated_at: i64,   // Last modification timestamp
+    pub bump: u8,          // PDA bump seed
+}
+
+impl ProgramConfig {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    pub fn is_paused(&self, subsystem: PauseSubsystem) -> bool {
+        self.paused & subsystem.mask() != 0
+    }
+
+    /// Combined mask for a list of subsystems
+    pub fn mask_of(subsystems: &[PauseSubsystem]) -> u8 {
+        subsystems.iter().fold(0, |mask, subsystem| mask | subsystem.mask())
+    }
+}
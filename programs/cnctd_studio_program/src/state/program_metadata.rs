@@ -1,10 +1,48 @@
 use anchor_lang::prelude::*;
 
+/// One of the mints recorded in program metadata
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, InitSpace)]
+pub enum CanonicalMint {
+    Usdc,
+    Cnctd,
+    Music,
+}
+
 #[account]
+#[derive(InitSpace)]
 pub struct ProgramMetadata {
     pub owner: Pubkey,       // Who owns the program (can modify settings)
     pub version: u8,         // Program version
     pub treasury_pda: Pubkey, // Treasury account
     pub updated_at: i64,     // Last modification timestamp
     pub bump: u8,            // PDA bump seed
-}
\ No newline at end of file
+    pub usdc_mint: Pubkey,   // Canonical USDC mint (SPL Token)
+    pub cnctd_mint: Pubkey,  // Canonical CNCTD mint (Token-2022)
+    pub music_mint: Pubkey,  // Canonical MUSIC mint (Token-2022)
+    pub fee_recipient: Pubkey, // USDC account that receives treasury fees
+    pub treasury_fee_bps: u16, // Treasury fee on new releases, in basis points of the price
+}
+
+impl ProgramMetadata {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Mints the treasury mints as purchase rewards
+    pub fn is_reward_mint(&self, mint: &Pubkey) -> bool {
+        *mint == self.cnctd_mint || *mint == self.music_mint
+    }
+
+    /// Any of the mints the program handles
+    pub fn is_canonical_mint(&self, mint: &Pubkey) -> bool {
+        *mint == self.usdc_mint || self.is_reward_mint(mint)
+    }
+
+    pub fn set_mint(&mut self, kind: CanonicalMint, mint: Pubkey) {
+        match kind {
+            CanonicalMint::Usdc => self.usdc_mint = mint,
+            CanonicalMint::Cnctd => self.cnctd_mint = mint,
+            CanonicalMint::Music => self.music_mint = mint,
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CnctdStudioError;
+
+pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReimbursementConfig {
+    pub max_per_tx: u64,  // Max reimbursement (fees and rent) per instruction in lamports (0 = unlimited)
+    pub max_per_day: u64, // Max reimbursement per admin per day in lamports (0 = unlimited)
+    pub updated_at: i64,  // Last modification timestamp
+    pub bump: u8,         // PDA bump seed
+}
+
+impl ReimbursementConfig {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminUsage {
+    pub admin: Pubkey,             // Admin whose reimbursements are tracked
+    pub day_start: i64,            // Start of the current UTC day
+    pub reimbursed_today: u64,     // Reimbursements paid out since day_start
+    pub lifetime_reimbursed: u64,  // Reimbursements paid out in total
+    pub bump: u8,                  // PDA bump seed
+}
+
+impl AdminUsage {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Records `amount` against the admin's limits, rejecting it if a ceiling would be exceeded
+    pub fn record(&mut self, config: &ReimbursementConfig, amount: u64, now: i64) -> Result<()> {
+        let today = now - now.rem_euclid(SECONDS_PER_DAY);
+        if today != self.day_start {
+            self.day_start = today;
+            self.reimbursed_today = 0;
+        }
+
+        let reimbursed_today = self.reimbursed_today
+            .checked_add(amount)
+            .ok_or(CnctdStudioError::MathOverflow)?;
+
+        require!(
+            config.max_per_tx == 0 || amount <= config.max_per_tx,
+            CnctdStudioError::ReimbursementLimitExceeded
+        );
+        require!(
+            config.max_per_day == 0 || reimbursed_today <= config.max_per_day,
+            CnctdStudioError::ReimbursementLimitExceeded
+        );
+
+        self.reimbursed_today = reimbursed_today;
+        self.lifetime_reimbursed = self.lifetime_reimbursed.saturating_add(amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::{get_associated_token_address, get_associated_token_address_with_program_id}, token_2022};
+
+use crate::{
+    arguments::release::{PaymentSplit, SplitRecipient, SplitShare},
+    constants::{BASIS_POINTS, MAX_ID_LEN, MAX_PAYMENT_SPLITS},
+    errors::CnctdStudioError,
+    math::apportion,
+    state::{band_pda::BandPDA, user_pda::UserPDA},
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Release {
+    #[max_len(MAX_ID_LEN)]
+    pub release_id: String,
+    pub authority: Pubkey,                 // Admin or artist that created the release (can update its terms)
+    pub price: u64,                        // Price in currency mint lamports
+    pub currency_mint: Pubkey,             // Mint the release is sold in (USDC)
+    pub treasury_fee_bps: u16,             // Treasury fee in basis points of the price
+    #[max_len(MAX_PAYMENT_SPLITS)]
+    pub split_shares: Vec<SplitShare>,     // Recipients of the price after the treasury fee
+    pub active: bool,                      // Whether the release can currently be purchased
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Release {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Treasury fee in currency lamports, rounded down
+    pub fn treasury_fee(&self) -> Result<u64> {
+        let fee = (self.price as u128)
+            .checked_mul(self.treasury_fee_bps as u128)
+            .ok_or(CnctdStudioError::MathOverflow)?
+            / BASIS_POINTS as u128;
+
+        Ok(fee as u64)
+    }
+
+    /// Converts the split shares into USDC amounts of the price after the treasury fee.
+    /// Rounding dust goes to the largest remainders, see `math::apportion`.
+    pub fn payment_splits(&self) -> Result<Vec<PaymentSplit>> {
+        let artist_total = self
+            .price
+            .checked_sub(self.treasury_fee()?)
+            .ok_or(CnctdStudioError::MathOverflow)?;
+        let weights: Vec<u64> = self.split_shares.iter().map(|share| share.share_bps as u64).collect();
+        let amounts = apportion(artist_total, &weights)?;
+
+        Ok(self
+            .split_shares
+            .iter()
+            .zip(amounts)
+            .map(|(share, amount)| PaymentSplit {
+                recipient_usdc_ata: share.recipient_usdc_ata,
+                recipient_cnctd_ata: share.recipient_cnctd_ata,
+                amount,
+            })
+            .collect())
+    }
+
+    /// Ensures the treasury fee is at most 100% and the split shares add up to exactly 100%
+    pub fn validate_terms(&self) -> Result<()> {
+        require!(
+            self.treasury_fee_bps as u64 <= BASIS_POINTS,
+            CnctdStudioError::InvalidTreasuryFee
+        );
+        require!(
+            !self.split_shares.is_empty(),
+            CnctdStudioError::InvalidPaymentSplits
+        );
+        require!(
+            self.split_shares.len() <= MAX_PAYMENT_SPLITS,
+            CnctdStudioError::TooManyPaymentSplits
+        );
+
+        let share_total: u64 = self.split_shares.iter().map(|share| share.share_bps as u64).sum();
+        require!(share_total == BASIS_POINTS, CnctdStudioError::InvalidSplitTotal);
+
+        Ok(())
+    }
+
+    /// Checks user and band split recipients against their registered accounts, passed as
+    /// `recipient_accounts` in split order (one per user or band recipient). Their USDC ATA must be
+    /// the one derived for the PDA; users accrue CNCTD to their derived ATA and bands to their
+    /// `cnctd_ata`.
+    pub fn verify_split_recipients(
+        &self,
+        recipient_accounts: &[AccountInfo],
+        usdc_mint: &Pubkey,
+        cnctd_mint: &Pubkey,
+    ) -> Result<()> {
+        let mut accounts = recipient_accounts.iter();
+
+        for share in &self.split_shares {
+            let (usdc_ata, cnctd_ata) = match &share.recipient {
+                SplitRecipient::User { user_pda } => {
+                    let info = accounts.next().ok_or(error!(CnctdStudioError::NotEnoughAccounts))?;
+                    require_keys_eq!(info.key(), *user_pda, CnctdStudioError::InvalidSplitRecipient);
+                    require_keys_eq!(*info.owner, crate::ID, CnctdStudioError::InvalidSplitRecipient);
+                    UserPDA::try_deserialize(&mut &info.data.borrow()[..])?;
+
+                    (
+                        get_associated_token_address(user_pda, usdc_mint),
+                        get_associated_token_address_with_program_id(user_pda, cnctd_mint, &token_2022::ID),
+                    )
+                }
+                SplitRecipient::Band { band_pda } => {
+                    let info = accounts.next().ok_or(error!(CnctdStudioError::NotEnoughAccounts))?;
+                    require_keys_eq!(info.key(), *band_pda, CnctdStudioError::InvalidSplitRecipient);
+                    require_keys_eq!(*info.owner, crate::ID, CnctdStudioError::InvalidSplitRecipient);
+                    let band = BandPDA::try_deserialize(&mut &info.data.borrow()[..])?;
+
+                    (get_associated_token_address(band_pda, usdc_mint), band.cnctd_ata)
+                }
+                SplitRecipient::External => continue,
+            };
+
+            require_keys_eq!(share.recipient_usdc_ata, usdc_ata, CnctdStudioError::InvalidSplitRecipient);
+            require_keys_eq!(share.recipient_cnctd_ata, cnctd_ata, CnctdStudioError::InvalidSplitRecipient);
+        }
+
+        Ok(())
+    }
+    /// Distinct bands this release pays
+    pub fn band_recipients(&self) -> Vec<Pubkey> {
+        let mut bands: Vec<Pubkey> = Vec::new();
+        for share in &self.split_shares {
+            if let SplitRecipient::Band { band_pda } = share.recipient {
+                if !bands.contains(&band_pda) {
+                    bands.push(band_pda);
+                }
+            }
+        }
+        bands
+    }
+
+    /// Counts a release for (`add`) or against each of `bands`, so a band can't be closed while
+    /// releases still pay it. The BandPDAs are looked up by key among `accounts` and must be writable.
+    pub fn count_band_refs(bands: &[Pubkey], accounts: &[AccountInfo], add: bool) -> Result<()> {
+        for band_key in bands {
+            let info = accounts
+                .iter()
+                .find(|info| info.key == band_key)
+                .ok_or(error!(CnctdStudioError::NotEnoughAccounts))?;
+            require_keys_eq!(*info.owner, crate::ID, CnctdStudioError::InvalidBandAccount);
+            require!(info.is_writable, CnctdStudioError::InvalidBandAccount);
+            require!(info.data_len() >= BandPDA::space(), CnctdStudioError::BandNotMigrated);
+
+            let mut band = BandPDA::try_deserialize(&mut &info.data.borrow()[..])?;
+            band.release_refs = match add {
+                true => band.release_refs.checked_add(1).ok_or(CnctdStudioError::MathOverflow)?,
+                false => band.release_refs.saturating_sub(1),
+            };
+            band.try_serialize(&mut &mut info.data.borrow_mut()[..])?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,25 +1,68 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::MAX_ID_LEN, errors::CnctdStudioError, pda, state::versioning::Versioned, utils::UuidFormatting};
+
 #[account]
+#[derive(InitSpace)]
 pub struct ReleaseAccess {
+    #[max_len(MAX_ID_LEN)]
     pub release_id: String,
+    #[max_len(MAX_ID_LEN)]
     pub buyer_id: String,
     pub created_at: i64,
     pub expiration_date: Option<i64>,
-    // pub bump: u8,
+    pub bump: u8, // PDA bump seed (0 on records created before it was stored)
+    pub version: u8, // Layout version
 }
 
 impl ReleaseAccess {
-    // Calculate space needed for the account
     pub fn space() -> usize {
-        // Use a more generous allocation approach
-        let size = 8 + // discriminator
-            32 + // release_id (max)
-            32 + // buyer_id (max)
-            8 + // created_at (i64 timestamp)
-            9 + // expiration_date (Option<i64>): 1 for variant + 8 for value
-            // 1 + // bump
-            16; // Extra padding for safety
-        size
+        8 + Self::INIT_SPACE
+    }
+
+    /// Whether `key` is the access record for `release_id` and `buyer_id`, under either the
+    /// 16-byte UUID seeds or the collision-prone 7-byte prefixes of older records
+    pub fn has_address(&self, key: &Pubkey, release_id: &str, buyer_id: &str) -> bool {
+        // Records that predate the stored bump have to search for it
+        if self.version < 1 {
+            return pda::release_access(release_id, buyer_id).0 == *key
+                || pda::legacy_release_access(release_id, buyer_id).0 == *key;
+        }
+
+        [
+            (release_id.to_uuid_bytes().to_vec(), buyer_id.to_uuid_bytes().to_vec()),
+            (release_id.to_7_byte_seed().to_vec(), buyer_id.to_7_byte_seed().to_vec()),
+        ]
+        .iter()
+        .any(|(release_seed, buyer_seed)| {
+            Pubkey::create_program_address(&[b"release_access", release_seed, buyer_seed, &[self.bump]], &crate::ID).as_ref() == Ok(key)
+        })
     }
-}
\ No newline at end of file
+}
+
+impl Versioned for ReleaseAccess {
+    const VERSION: u8 = 1;
+
+    fn current_space() -> usize {
+        ReleaseAccess::space()
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, key: &Pubkey) -> Result<()> {
+        // Records from before the bump was stored: recover it from whichever seed scheme matches
+        if self.version < 1 {
+            let (address, bump) = pda::release_access(&self.release_id, &self.buyer_id);
+            let (legacy_address, legacy_bump) = pda::legacy_release_access(&self.release_id, &self.buyer_id);
+            self.bump = match *key {
+                k if k == address => bump,
+                k if k == legacy_address => legacy_bump,
+                _ => return err!(CnctdStudioError::InvalidReleaseAccessAccount),
+            };
+        }
+        self.version = Self::VERSION;
+        Ok(())
+    }
+}
@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{close_account, transfer, CloseAccount, TokenAccount, Transfer};
 
-use crate::arguments::release::PaymentSplit;
+use crate::{arguments::release::PaymentSplit, constants::{DEFAULT_ESCROW_FULFILLMENT_WINDOW, MAX_ID_LEN, MAX_PAYMENT_SPLITS}, errors::CnctdStudioError, state::versioning::Versioned, utils::UuidFormatting};
 
 #[account]
+#[derive(InitSpace)]
 pub struct ReleaseEscrow {
+    #[max_len(MAX_ID_LEN)]
     pub release_id: String,
+    #[max_len(MAX_ID_LEN)]
     pub buyer_id: String,
     pub treasury_fee: u64,
+    #[max_len(MAX_PAYMENT_SPLITS)]
     pub payment_splits: Vec<PaymentSplit>,
     pub total_amount: u64,
     pub payments_fulfilled: bool,  // Payments have been sent to treasury and artists
@@ -15,25 +20,133 @@ pub struct ReleaseEscrow {
     pub fulfilled: bool,           // Overall completion flag (escrow can be closed)
     pub purchase_date: i64,
     pub bump: u8,
+    pub expires_at: i64,           // Deadline for fulfillment, after which anyone can reclaim (0 on escrows opened before expiry existed)
+    pub version: u8,               // Layout version
 }
 
 impl ReleaseEscrow {
-    // Calculate space needed for the account
     pub fn space() -> usize {
-        // Base size for fixed fields
-        let size = 8 + // discriminator
-                   32 + // release_id (max)
-                   32 + // buyer_id (max)
-                   8 + // treasury_fee
-                   4 + // payment_splits vec length
-                   (10 * (32 + 32 + 8)) + // Up to 10 payment splits
-                   8 + // total_amount
-                   1 + // payments_fulfilled
-                   1 + // nft_minted
-                   1 + // rewards_paid
-                   1 + // fulfilled
-                   8 + // purchase_date (i64 timestamp)
-                   1; // bump
-        size
-    }
-}
\ No newline at end of file
+        8 + Self::INIT_SPACE
+    }
+
+    /// Fulfillment deadline. Escrows opened before expiry was tracked fall back to the default window.
+    pub fn fulfillment_deadline(&self) -> i64 {
+        if self.expires_at > 0 {
+            self.expires_at
+        } else {
+            self.purchase_date.saturating_add(DEFAULT_ESCROW_FULFILLMENT_WINDOW)
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.fulfillment_deadline()
+    }
+
+    /// Release and buyer seed components. New escrows use the full 16-byte UUIDs;
+    /// `legacy` selects the 7-byte prefixes used by escrows opened before that, which can collide.
+    pub fn seed_ids(release_id: &str, buyer_id: &str, legacy: bool) -> (Vec<u8>, Vec<u8>) {
+        match legacy {
+            true => (release_id.to_7_byte_seed().to_vec(), buyer_id.to_7_byte_seed().to_vec()),
+            false => (release_id.to_uuid_bytes().to_vec(), buyer_id.to_uuid_bytes().to_vec()),
+        }
+    }
+
+    /// Whether this escrow belongs to `release_id` and `buyer_id`
+    pub fn is_for(&self, release_id: &str, buyer_id: &str) -> bool {
+        self.release_id.to_solana_seed_format() == release_id.to_solana_seed_format()
+            && self.buyer_id.to_solana_seed_format() == buyer_id.to_solana_seed_format()
+    }
+
+    /// Seed components for signing as the escrow at `key`, under whichever scheme it was opened with
+    pub fn signer_seed_ids(&self, key: &Pubkey) -> Result<(Vec<u8>, Vec<u8>)> {
+        for legacy in [false, true] {
+            let (release_seed, buyer_seed) = Self::seed_ids(&self.release_id, &self.buyer_id, legacy);
+            let address = Pubkey::create_program_address(
+                &[b"release_escrow", &release_seed, &buyer_seed, &[self.bump]],
+                &crate::ID,
+            );
+            if address.as_ref() == Ok(key) {
+                return Ok((release_seed, buyer_seed));
+            }
+        }
+
+        err!(CnctdStudioError::InvalidEscrowAccount)
+    }
+
+    pub fn has_address(&self, key: &Pubkey) -> bool {
+        self.signer_seed_ids(key).is_ok()
+    }
+    /// Returns the escrowed USDC to the buyer and closes the escrow token account,
+    /// sending its rent to the treasury. The escrow account itself is closed by the caller.
+    /// The token account's actual balance is refunded rather than `total_amount`, so tokens
+    /// sent to it from outside can't leave it non-empty and block the close.
+    pub fn refund_buyer<'info>(
+        &self,
+        escrow_info: &AccountInfo<'info>,
+        escrow_usdc_ata: &AccountInfo<'info>,
+        buyer_usdc_ata: &AccountInfo<'info>,
+        treasury_info: &AccountInfo<'info>,
+        token_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        let (release_seed, buyer_seed) = self.signer_seed_ids(escrow_info.key)?;
+        let escrow_seeds: &[&[u8]] = &[
+            b"release_escrow",
+            release_seed.as_ref(),
+            buyer_seed.as_ref(),
+            &[self.bump],
+        ];
+
+        let balance = TokenAccount::try_deserialize(&mut &escrow_usdc_ata.data.borrow()[..])?.amount;
+        if balance > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    Transfer {
+                        from: escrow_usdc_ata.clone(),
+                        to: buyer_usdc_ata.clone(),
+                        authority: escrow_info.clone(),
+                    },
+                    &[escrow_seeds],
+                ),
+                balance,
+            )?;
+
+            msg!("Refunded {} USDC to buyer", balance);
+        }
+
+        close_account(CpiContext::new_with_signer(
+            token_program.clone(),
+            CloseAccount {
+                account: escrow_usdc_ata.clone(),
+                destination: treasury_info.clone(),
+                authority: escrow_info.clone(),
+            },
+            &[escrow_seeds],
+        ))?;
+
+        msg!("Escrow USDC token account closed");
+
+        Ok(())
+    }
+}
+
+impl Versioned for ReleaseEscrow {
+    const VERSION: u8 = 1;
+
+    fn current_space() -> usize {
+        ReleaseEscrow::space()
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _key: &Pubkey) -> Result<()> {
+        // Escrows from before expiry was tracked get the default window made explicit
+        if self.expires_at == 0 {
+            self.expires_at = self.fulfillment_deadline();
+        }
+        self.version = Self::VERSION;
+        Ok(())
+    }
+}
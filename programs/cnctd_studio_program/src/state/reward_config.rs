@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::BASIS_POINTS, errors::CnctdStudioError};
+
+#[account]
+#[derive(InitSpace)]
+pub struct RewardConfig {
+    pub enabled: bool,          // Master switch for MUSIC and CNCTD purchase rewards
+    pub buyer_rate_bps: u32,    // MUSIC minted to the buyer per USDC paid, in basis points (10_000 = 1:1)
+    pub artist_rate_bps: u32,   // CNCTD minted to artists per USDC paid, in basis points (10_000 = 1:1)
+    pub buyer_cap_per_tx: u64,  // Max MUSIC minted to the buyer per purchase (0 = uncapped)
+    pub artist_cap_per_tx: u64, // Max CNCTD minted to all artists per purchase (0 = uncapped)
+    pub updated_at: i64,        // Last modification timestamp
+    pub bump: u8,               // PDA bump seed
+}
+
+impl RewardConfig {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// MUSIC reward for the buyer of a purchase worth `payment` USDC
+    pub fn buyer_reward(&self, payment: u64) -> Result<u64> {
+        self.reward(payment, self.buyer_rate_bps, self.buyer_cap_per_tx)
+    }
+
+    /// Total CNCTD reward shared by the artists of a purchase worth `payment` USDC
+    pub fn artist_reward(&self, payment: u64) -> Result<u64> {
+        self.reward(payment, self.artist_rate_bps, self.artist_cap_per_tx)
+    }
+
+    fn reward(&self, payment: u64, rate_bps: u32, cap: u64) -> Result<u64> {
+        if !self.enabled {
+            return Ok(0);
+        }
+
+        let reward = (payment as u128)
+            .checked_mul(rate_bps as u128)
+            .ok_or(CnctdStudioError::MathOverflow)?
+            / BASIS_POINTS as u128;
+        let reward = u64::try_from(reward).map_err(|_| CnctdStudioError::MathOverflow)?;
+
+        Ok(if cap > 0 { reward.min(cap) } else { reward })
+    }
+}
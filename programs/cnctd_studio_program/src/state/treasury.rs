@@ -1,27 +1,96 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::MAX_ADMINS, errors::CnctdStudioError, state::reimbursement::{AdminUsage, ReimbursementConfig}};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminRole {
+    SuperAdmin, // Manages admins, roles and program configuration; implies every other role
+    Operator,   // Runs purchase flows: escrows, access, user and band onboarding
+    Minter,     // Mints tokens from the treasury
+    Treasurer,  // Moves tokens out of the treasury
+}
+
+impl AdminRole {
+    pub fn mask(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, InitSpace)]
+pub struct AdminRoles {
+    pub admin: Pubkey,
+    pub roles: u8, // Bitmask of AdminRole::mask()
+}
+
 #[account]
+#[derive(InitSpace)]
 pub struct Treasury {
+    #[max_len(MAX_ADMINS)]
     pub admins: Vec<Pubkey>, // Multiple admins
     pub bump: u8, 
     pub version: u8,
+    #[max_len(MAX_ADMINS)]
+    pub roles: Vec<AdminRoles>, // Role grants, empty on treasuries that predate roles
 }
 
 impl Treasury {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
     pub fn is_admin(&self, pubkey: &Pubkey) -> bool {
         self.admins.iter().any(|admin| admin == pubkey)
     }
+
+    /// Whether `pubkey` is an admin holding `role` (or SuperAdmin).
+    /// Until the first role is granted every admin keeps full access, so existing treasuries keep working.
+    pub fn has_role(&self, pubkey: &Pubkey, role: AdminRole) -> bool {
+        if !self.is_admin(pubkey) {
+            return false;
+        }
+        if self.roles.is_empty() {
+            return true;
+        }
+
+        let mask = role.mask() | AdminRole::SuperAdmin.mask();
+        self.roles
+            .iter()
+            .any(|grant| grant.admin == *pubkey && grant.roles & mask != 0)
+    }
+
+    pub fn super_admin_count(&self) -> usize {
+        self.roles
+            .iter()
+            .filter(|grant| grant.roles & AdminRole::SuperAdmin.mask() != 0)
+            .count()
+    }
+
+    /// Pays the admin back for transaction fees and rent they fronted.
+    /// Fee compensation and rent together are held to the configured per-transaction and per-day
+    /// limits, and the treasury is never left below rent-exempt.
     pub fn reimburse_admin<'info>(
         &self,
         treasury_info: &AccountInfo<'info>,
         admin_info: &AccountInfo<'info>,
+        reimbursement_config: &ReimbursementConfig,
+        admin_usage: &mut Account<'info, AdminUsage>,
         fee_compensation: Option<u64>,
         additional_rent: Option<u64>,
     ) -> Result<()> {
         let fee_compensation = fee_compensation.unwrap_or(0);
         let additional_rent = additional_rent.unwrap_or(0);
-        
-        let total_reimbursement = fee_compensation + additional_rent;
+
+        // Usage accounts are created on first use
+        if admin_usage.admin == Pubkey::default() {
+            let (_, bump) = Pubkey::find_program_address(&[b"admin_usage", admin_info.key.as_ref()], &crate::ID);
+            admin_usage.admin = admin_info.key();
+            admin_usage.bump = bump;
+        }
+
+        let total_reimbursement = fee_compensation
+            .checked_add(additional_rent)
+            .ok_or(CnctdStudioError::MathOverflow)?;
+        admin_usage.record(reimbursement_config, total_reimbursement, Clock::get()?.unix_timestamp)?;
     
         msg!("Starting reimbursement process:");
         msg!(" - Fee compensation: {} lamports", fee_compensation);
@@ -30,8 +99,20 @@ impl Treasury {
         msg!("Treasury balance before reimbursement: {} lamports", treasury_info.lamports());
         msg!("Admin balance before reimbursement: {} lamports", admin_info.lamports());
     
-        **treasury_info.try_borrow_mut_lamports()? -= total_reimbursement;
-        **admin_info.try_borrow_mut_lamports()? += total_reimbursement;
+        let treasury_balance = treasury_info.lamports()
+            .checked_sub(total_reimbursement)
+            .ok_or(CnctdStudioError::InsufficientFunds)?;
+        // The treasury must stay rent-exempt or the runtime would reject the transaction
+        require!(
+            treasury_balance >= Rent::get()?.minimum_balance(treasury_info.data_len()),
+            CnctdStudioError::InsufficientFunds
+        );
+        let admin_balance = admin_info.lamports()
+            .checked_add(total_reimbursement)
+            .ok_or(CnctdStudioError::MathOverflow)?;
+
+        **treasury_info.try_borrow_mut_lamports()? = treasury_balance;
+        **admin_info.try_borrow_mut_lamports()? = admin_balance;
     
         msg!("Treasury balance after reimbursement: {} lamports", treasury_info.lamports());
         msg!("Admin balance after reimbursement: {} lamports", admin_info.lamports());
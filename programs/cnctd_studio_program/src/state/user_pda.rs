@@ -1,6 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address_with_program_id, token_2022};
+
+use crate::{errors::CnctdStudioError, pda, state::{program_metadata::ProgramMetadata, versioning::Versioned}};
 
 #[account]
+#[derive(InitSpace)]
 pub struct UserPDA {
     pub admin: Pubkey,        // Always your program's treasury PDA
     pub auth: Option<Pubkey>,        // User's wallet (can change ATAs)
@@ -13,4 +17,80 @@ pub struct UserPDA {
     pub fees_waived: u64, // Fees that have been waived
     pub waived_count: u64, // Number of waived transactions
     pub bump: u8, // PDA bump seed
-}
\ No newline at end of file
+    pub version: u8, // Layout version
+}
+
+impl UserPDA {
+    pub fn space() -> usize {
+        8 + Self::INIT_SPACE
+    }
+
+    /// Seed component for signing as the user at `key`. Users created before 16-byte UUID seeds
+    /// are derived from the raw id string.
+    pub fn signer_seed_id(&self, key: &Pubkey, user_id: &str) -> Result<Vec<u8>> {
+        pda::id_seed(b"user", user_id, key, self.bump).ok_or(error!(CnctdStudioError::InvalidUserAccount))
+    }
+
+    /// Program-derived Token-2022 ATA holding the user's `mint` rewards
+    pub fn reward_ata(key: &Pubkey, mint: &Pubkey) -> Pubkey {
+        get_associated_token_address_with_program_id(key, mint, &token_2022::ID)
+    }
+
+    /// Whether MUSIC rewards for the user at `key` may go to `destination`: their custody account
+    /// when set, or the derived ATA as the fallback should the custody account have been closed
+    pub fn accepts_music_reward(&self, key: &Pubkey, music_mint: &Pubkey, destination: &Pubkey) -> bool {
+        self.music_cust == Some(*destination) || Self::reward_ata(key, music_mint) == *destination
+    }
+
+    /// Whether CNCTD rewards for the user at `key` may go to `destination`, on the same terms as MUSIC
+    pub fn accepts_cnctd_reward(&self, key: &Pubkey, cnctd_mint: &Pubkey, destination: &Pubkey) -> bool {
+        self.cnctd_cust == Some(*destination) || Self::reward_ata(key, cnctd_mint) == *destination
+    }
+
+    /// The user's custody account for `mint`, if they have set one
+    pub fn custody_account(&self, mint: &Pubkey, program_metadata: &ProgramMetadata) -> Option<Pubkey> {
+        match *mint {
+            m if m == program_metadata.usdc_mint => self.usdc_cust,
+            m if m == program_metadata.cnctd_mint => self.cnctd_cust,
+            m if m == program_metadata.music_mint => self.music_cust,
+            _ => None,
+        }
+    }
+
+    /// Whether a balance of `mint` left behind when the user is closed may be swept to
+    /// `destination`: their custody account for the mint, or their bound wallet's ATA
+    pub fn is_sweep_destination(
+        &self,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        program_metadata: &ProgramMetadata,
+        destination: &Pubkey,
+    ) -> bool {
+        self.custody_account(mint, program_metadata) == Some(*destination)
+            || self.auth.is_some_and(|auth| {
+                get_associated_token_address_with_program_id(&auth, mint, token_program) == *destination
+            })
+    }
+
+    /// Whether `key` is the PDA of `user_id` under either seed scheme
+    pub fn has_address(&self, key: &Pubkey, user_id: &str) -> bool {
+        pda::id_seed(b"user", user_id, key, self.bump).is_some()
+    }
+}
+
+impl Versioned for UserPDA {
+    const VERSION: u8 = 1;
+
+    fn current_space() -> usize {
+        UserPDA::space()
+    }
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self, _key: &Pubkey) -> Result<()> {
+        self.version = Self::VERSION;
+        Ok(())
+    }
+}
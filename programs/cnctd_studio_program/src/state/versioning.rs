@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Accounts whose layout can be upgraded in place by `migrate_account`.
+/// Fields are only ever appended, so once an older account has been reallocated to the
+/// current size its missing fields read as zero and `upgrade` fills them in.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    /// Layout version written by this build. Accounts created before versioning read as 0.
+    const VERSION: u8;
+
+    /// Space needed for the current layout
+    fn current_space() -> usize;
+
+    fn version(&self) -> u8;
+
+    /// Populate fields added since the stored version and stamp the account as current
+    fn upgrade(&mut self, key: &Pubkey) -> Result<()>;
+}
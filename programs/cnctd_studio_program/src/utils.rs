@@ -1,3 +1,7 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_ID_LEN, errors::CnctdStudioError};
+
 pub trait UuidFormatting {
     /// Removes hyphens from a UUID string to make it compatible with Solana's 32-byte seed limit
     fn to_solana_seed_format(&self) -> String;
@@ -16,6 +20,10 @@ pub trait UuidFormatting {
     
     /// Converts a UUID string to a truncated seed with fixed size N
     fn to_fixed_seed<const N: usize>(&self) -> [u8; N];
+
+    /// Converts a UUID string to its 16 raw bytes, for collision-free seeds.
+    /// Non-hex input yields zero nibbles, so validate with `is_valid_uuid` first.
+    fn to_uuid_bytes(&self) -> [u8; 16];
 }
 
 impl UuidFormatting for str {
@@ -86,6 +94,22 @@ impl UuidFormatting for str {
         
         result
     }
+
+    fn to_uuid_bytes(&self) -> [u8; 16] {
+        let formatted = self.to_solana_seed_format();
+        let nibbles: Vec<u8> = formatted
+            .chars()
+            .take(32)
+            .map(|c| c.to_digit(16).unwrap_or(0) as u8)
+            .collect();
+
+        let mut result = [0u8; 16];
+        for (i, pair) in nibbles.chunks(2).enumerate() {
+            result[i] = (pair[0] << 4) | pair.get(1).copied().unwrap_or(0);
+        }
+
+        result
+    }
 }
 
 impl UuidFormatting for String {
@@ -112,4 +136,14 @@ impl UuidFormatting for String {
     fn to_fixed_seed<const N: usize>(&self) -> [u8; N] {
         self.as_str().to_fixed_seed()
     }
+
+    fn to_uuid_bytes(&self) -> [u8; 16] {
+        self.as_str().to_uuid_bytes()
+    }
+}
+
+/// Rejects ids longer than accounts reserve for them, rather than failing later at serialization
+pub fn require_id_len(id: &str) -> Result<()> {
+    require!(id.len() <= MAX_ID_LEN, CnctdStudioError::IdTooLong);
+    Ok(())
 }
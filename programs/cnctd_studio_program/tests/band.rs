@@ -0,0 +1,162 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::instruction::Instruction,
+};
+use anchor_spl::{token, token_2022};
+use cnctd_studio_program::{
+    accounts, instruction,
+    errors::CnctdStudioError,
+    pda,
+    state::program_config::PauseSubsystem,
+};
+use common::{program_error, uuid, TestEnv};
+
+// 1_000_001 doesn't divide evenly between the 70/30 shares
+const BALANCE: u64 = 1_000_001;
+
+struct Band {
+    band_id: String,
+    band: Pubkey,
+    members: Vec<Pubkey>,
+}
+
+impl Band {
+    fn new(env: &mut TestEnv, shares: &[u16], usdc: u64, cnctd: u64) -> Self {
+        let band_id = uuid();
+        let members: Vec<Pubkey> = shares.iter().map(|_| env.create_user(&uuid(), 0)).collect();
+        let member_shares: Vec<(Pubkey, u16)> = members.iter().copied().zip(shares.iter().copied()).collect();
+        let band = env.create_band(&band_id, &member_shares, usdc, cnctd);
+
+        Band { band_id, band, members }
+    }
+
+    fn member_atas(&self, env: &TestEnv, mint: &Pubkey) -> Vec<AccountMeta> {
+        self.members.iter().map(|member| AccountMeta::new(env.ata(member, mint), false)).collect()
+    }
+
+    fn member_balances(&self, env: &TestEnv, mint: &Pubkey) -> Vec<u64> {
+        self.members.iter().map(|member| env.token_balance(&env.ata(member, mint))).collect()
+    }
+
+    fn distribute(&self, env: &TestEnv) -> Instruction {
+        TestEnv::instruction(
+            accounts::DistributeBandRevenue {
+                admin: env.operator,
+                treasury: pda::treasury().0,
+                program_config: pda::program_config().0,
+                program_metadata: pda::program_metadata().0,
+                band_pda: self.band,
+                usdc_mint: env.usdc_mint,
+                band_usdc_ata: env.ata(&self.band, &env.usdc_mint),
+                token_program: token::ID,
+            },
+            instruction::DistributeBandRevenue { band_id: self.band_id.clone() },
+            self.member_atas(env, &env.usdc_mint),
+        )
+    }
+
+    fn transfer_assets(&self, env: &TestEnv) -> Instruction {
+        let treasury = pda::treasury().0;
+        let mut remaining = self.member_atas(env, &env.usdc_mint);
+        remaining.extend(self.member_atas(env, &env.cnctd_mint));
+
+        TestEnv::instruction(
+            accounts::TransferBandAssets {
+                admin: env.super_admin,
+                treasury,
+                program_config: pda::program_config().0,
+                program_metadata: pda::program_metadata().0,
+                band_pda: self.band,
+                usdc_mint: env.usdc_mint,
+                cnctd_mint: env.cnctd_mint,
+                band_usdc_ata: env.ata(&self.band, &env.usdc_mint),
+                band_cnctd_ata: env.ata(&self.band, &env.cnctd_mint),
+                treasury_usdc_ata: env.ata(&treasury, &env.usdc_mint),
+                treasury_cnctd_ata: env.ata(&treasury, &env.cnctd_mint),
+                token_program: token::ID,
+                token_2022_program: token_2022::ID,
+            },
+            instruction::TransferBandAssets { band_id: self.band_id.clone() },
+            remaining,
+        )
+    }
+}
+
+#[test]
+fn distribute_pays_members_by_share() {
+    let mut env = TestEnv::new();
+    let band = Band::new(&mut env, &[7_000, 3_000], BALANCE, 0);
+
+    env.process(&band.distribute(&env), &[env.operator]).unwrap();
+
+    // 700_000.7 takes the dust over 300_000.3
+    assert_eq!(band.member_balances(&env, &env.usdc_mint), vec![700_001, 300_000]);
+    assert_eq!(env.token_balance(&env.ata(&band.band, &env.usdc_mint)), 0);
+}
+
+#[test]
+fn distribute_rejects_a_substituted_member_account() {
+    let mut env = TestEnv::new();
+    let band = Band::new(&mut env, &[7_000, 3_000], BALANCE, 0);
+    let thief = env.create_user(&uuid(), 0);
+
+    let mut distribute = band.distribute(&env);
+    let last = distribute.accounts.len() - 1;
+    distribute.accounts[last].pubkey = env.ata(&thief, &env.usdc_mint);
+
+    assert_eq!(
+        env.process(&distribute, &[env.operator]),
+        Err(program_error(CnctdStudioError::InvalidPaymentReceiver))
+    );
+}
+
+#[test]
+fn distribute_is_blocked_while_withdrawals_are_paused() {
+    let mut env = TestEnv::new();
+    let band = Band::new(&mut env, &[7_000, 3_000], BALANCE, 0);
+    env.pause(PauseSubsystem::Withdrawals.mask());
+
+    assert_eq!(
+        env.process(&band.distribute(&env), &[env.operator]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+    assert_eq!(env.token_balance(&env.ata(&band.band, &env.usdc_mint)), BALANCE);
+}
+
+#[test]
+fn transfer_assets_sweeps_member_shares_and_sends_the_rest_to_the_treasury() {
+    let mut env = TestEnv::new();
+    // Shares mid-edit add up to 90%, the unallocated 10% belongs to the treasury
+    let band = Band::new(&mut env, &[6_000, 3_000], BALANCE, 2_000);
+    let treasury = pda::treasury().0;
+    let (usdc_mint, cnctd_mint) = (env.usdc_mint, env.cnctd_mint);
+    let treasury_usdc = env.create_ata(treasury, usdc_mint, 0);
+    let treasury_cnctd = env.create_ata(treasury, cnctd_mint, 0);
+
+    env.process(&band.transfer_assets(&env), &[env.super_admin]).unwrap();
+
+    assert_eq!(band.member_balances(&env, &env.usdc_mint), vec![600_001, 300_000]);
+    assert_eq!(env.token_balance(&treasury_usdc), 100_000);
+    assert_eq!(band.member_balances(&env, &env.cnctd_mint), vec![1_200, 600]);
+    assert_eq!(env.token_balance(&treasury_cnctd), 200);
+    assert_eq!(env.token_balance(&env.ata(&band.band, &env.usdc_mint)), 0);
+    assert_eq!(env.token_balance(&env.ata(&band.band, &env.cnctd_mint)), 0);
+}
+
+#[test]
+fn transfer_assets_is_blocked_while_admin_ops_are_paused() {
+    let mut env = TestEnv::new();
+    let band = Band::new(&mut env, &[7_000, 3_000], BALANCE, 0);
+    let treasury = pda::treasury().0;
+    let (usdc_mint, cnctd_mint) = (env.usdc_mint, env.cnctd_mint);
+    env.create_ata(treasury, usdc_mint, 0);
+    env.create_ata(treasury, cnctd_mint, 0);
+    env.pause(PauseSubsystem::AdminOps.mask());
+
+    assert_eq!(
+        env.process(&band.transfer_assets(&env), &[env.super_admin]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+}
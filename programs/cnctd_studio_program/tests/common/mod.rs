@@ -0,0 +1,871 @@
+//! In-memory runtime for driving the program's instructions end to end. Accounts are serialized
+//! into the same input buffer the loader builds and handed to the program entrypoint; CPIs to the
+//! system, SPL Token, Token-2022 and associated token programs are carried out by stubs that
+//! enforce the same ownership, signer and writability rules as the real programs.
+#![allow(dead_code)]
+
+use std::{cell::Cell, collections::HashMap, mem::size_of, sync::Once};
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        entrypoint::{deserialize, MAX_PERMITTED_DATA_INCREASE, NON_DUP_MARKER},
+        instruction::Instruction,
+        program_option::COption,
+        program_pack::Pack,
+        program_stubs::{set_syscall_stubs, SyscallStubs},
+        system_program, sysvar,
+    },
+    InstructionData, ToAccountMetas,
+};
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id},
+    token::{self, spl_token},
+    token_2022,
+};
+use cnctd_studio_program::{
+    arguments::release::{SplitRecipient, SplitShare},
+    constants::BASIS_POINTS,
+    errors::CnctdStudioError,
+    pda,
+    state::{
+        band_pda::{BandMember, BandPDA},
+        emission_budget::EmissionBudget,
+        governance::Governance,
+        program_config::ProgramConfig,
+        program_metadata::ProgramMetadata,
+        reimbursement::ReimbursementConfig,
+        release::Release,
+        reward_config::RewardConfig,
+        treasury::{AdminRole, AdminRoles, Treasury},
+        user_pda::UserPDA,
+        versioning::Versioned,
+    },
+    utils::UuidFormatting,
+};
+
+pub const START_TIME: i64 = 1_700_000_000;
+pub const SOL: u64 = 1_000_000_000;
+pub const TREASURY_LAMPORTS: u64 = 100 * SOL;
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(START_TIME) };
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccountState {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+/// Error a failing instruction returns for one of the program's own errors
+pub fn program_error(error: CnctdStudioError) -> ProgramError {
+    anchor_lang::error::Error::from(error).into()
+}
+
+pub fn rent() -> Rent {
+    Rent::default()
+}
+
+pub struct TestEnv {
+    accounts: HashMap<Pubkey, AccountState>,
+    pub super_admin: Pubkey,
+    pub operator: Pubkey,
+    pub treasurer: Pubkey,
+    pub guardian: Pubkey,
+    pub usdc_mint: Pubkey,
+    pub cnctd_mint: Pubkey,
+    pub music_mint: Pubkey,
+    pub fee_recipient: Pubkey,
+}
+
+impl TestEnv {
+    /// Fully configured program: treasury with a super admin, an operator and a treasurer,
+    /// canonical mints, a fee recipient, rewards enabled and no pause bits or reimbursement limits
+    pub fn new() -> Self {
+        install_stubs();
+        NOW.with(|now| now.set(START_TIME));
+
+        let mut env = TestEnv {
+            accounts: HashMap::new(),
+            super_admin: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            treasurer: Pubkey::new_unique(),
+            guardian: Pubkey::new_unique(),
+            usdc_mint: Pubkey::new_unique(),
+            cnctd_mint: Pubkey::new_unique(),
+            music_mint: Pubkey::new_unique(),
+            fee_recipient: Pubkey::new_unique(),
+        };
+
+        for program in [
+            cnctd_studio_program::ID,
+            system_program::ID,
+            token::ID,
+            token_2022::ID,
+            associated_token::ID,
+        ] {
+            env.set_account(program, AccountState { lamports: 1, executable: true, ..Default::default() });
+        }
+        env.set_account(sysvar::rent::ID, AccountState {
+            lamports: 1,
+            data: rent_sysvar_data(),
+            owner: sysvar::ID,
+            executable: false,
+        });
+
+        for admin in [env.super_admin, env.operator, env.treasurer, env.guardian] {
+            env.fund(&admin, 10 * SOL);
+        }
+
+        let treasury = pda::treasury().0;
+        env.create_mint(env.usdc_mint, token::ID, None, 6);
+        env.create_mint(env.cnctd_mint, token_2022::ID, Some(treasury), 6);
+        env.create_mint(env.music_mint, token_2022::ID, Some(treasury), 6);
+        env.create_token_account(env.fee_recipient, env.usdc_mint, treasury, token::ID, 0);
+
+        env.set_state(treasury, &Treasury {
+            admins: vec![env.super_admin, env.operator, env.treasurer],
+            bump: pda::treasury().1,
+            version: 1,
+            roles: vec![
+                AdminRoles { admin: env.super_admin, roles: AdminRole::SuperAdmin.mask() },
+                AdminRoles { admin: env.operator, roles: AdminRole::Operator.mask() },
+                AdminRoles { admin: env.treasurer, roles: AdminRole::Treasurer.mask() },
+            ],
+        }, Treasury::space());
+        env.set_lamports(&treasury, TREASURY_LAMPORTS);
+
+        env.set_state(pda::program_metadata().0, &ProgramMetadata {
+            owner: env.super_admin,
+            version: 1,
+            treasury_pda: treasury,
+            updated_at: START_TIME,
+            bump: pda::program_metadata().1,
+            usdc_mint: env.usdc_mint,
+            cnctd_mint: env.cnctd_mint,
+            music_mint: env.music_mint,
+            fee_recipient: env.fee_recipient,
+            treasury_fee_bps: 1_000,
+        }, ProgramMetadata::space());
+
+        env.set_state(pda::program_config().0, &ProgramConfig {
+            guardian: env.guardian,
+            paused: 0,
+            updated_at: START_TIME,
+            bump: pda::program_config().1,
+        }, ProgramConfig::space());
+
+        env.set_state(pda::reimbursement_config().0, &ReimbursementConfig {
+            max_per_tx: 0,
+            max_per_day: 0,
+            updated_at: START_TIME,
+            bump: pda::reimbursement_config().1,
+        }, ReimbursementConfig::space());
+
+        env.set_state(pda::reward_config().0, &RewardConfig {
+            enabled: true,
+            buyer_rate_bps: BASIS_POINTS as u32,
+            artist_rate_bps: BASIS_POINTS as u32,
+            buyer_cap_per_tx: 0,
+            artist_cap_per_tx: 0,
+            updated_at: START_TIME,
+            bump: pda::reward_config().1,
+        }, RewardConfig::space());
+
+        for mint in [env.cnctd_mint, env.music_mint] {
+            env.set_state(pda::emission_budget(&mint).0, &EmissionBudget {
+                mint,
+                epoch_duration: 24 * 60 * 60,
+                epoch_budget: 0,
+                lifetime_cap: 0,
+                scale_down: false,
+                epoch_start: START_TIME,
+                epoch_minted: 0,
+                lifetime_minted: 0,
+                updated_at: START_TIME,
+                bump: pda::emission_budget(&mint).1,
+            }, EmissionBudget::space());
+        }
+
+        env.set_state(pda::governance().0, &Governance {
+            approval_threshold: 1,
+            proposal_count: 0,
+            updated_at: START_TIME,
+            bump: pda::governance().1,
+            admin_change_delay: 0,
+        }, Governance::space());
+
+        env
+    }
+
+    // --- Clock ---
+
+    pub fn now(&self) -> i64 {
+        NOW.with(|now| now.get())
+    }
+
+    pub fn advance_time(&self, seconds: i64) {
+        NOW.with(|now| now.set(now.get() + seconds));
+    }
+
+    // --- Account access ---
+
+    pub fn set_account(&mut self, key: Pubkey, account: AccountState) {
+        self.accounts.insert(key, account);
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&AccountState> {
+        self.accounts.get(key)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts.contains_key(key)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn set_lamports(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts.entry(*key).or_insert_with(system_account).lamports = lamports;
+    }
+
+    pub fn fund(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts.entry(*key).or_insert_with(system_account).lamports += lamports;
+    }
+
+    /// Writes program state at `key` with `space` bytes, funded to be rent-exempt
+    pub fn set_state<T: AccountSerialize>(&mut self, key: Pubkey, state: &T, space: usize) {
+        let mut data = Vec::with_capacity(space);
+        state.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "state for {key} is larger than its space");
+        data.resize(space, 0);
+
+        let lamports = self.lamports(&key).max(rent().minimum_balance(space));
+        self.set_account(key, AccountState { lamports, data, owner: cnctd_studio_program::ID, executable: false });
+    }
+
+    /// Reads program state at `key`, after updating it with `update`
+    pub fn update_state<T: AccountSerialize + AccountDeserialize>(&mut self, key: Pubkey, update: impl FnOnce(&mut T)) {
+        let mut state: T = self.state(&key);
+        update(&mut state);
+        let account = self.accounts.get_mut(&key).unwrap();
+        state.try_serialize(&mut &mut account.data[..]).unwrap();
+    }
+
+    pub fn state<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).unwrap_or_else(|| panic!("no account at {key}"));
+        T::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    // --- Tokens ---
+
+    pub fn create_mint(&mut self, key: Pubkey, token_program: Pubkey, authority: Option<Pubkey>, decimals: u8) {
+        let mint = spl_token::state::Mint {
+            mint_authority: authority.map_or(COption::None, COption::Some),
+            supply: 0,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        spl_token::state::Mint::pack(mint, &mut data).unwrap();
+        self.set_account(key, AccountState {
+            lamports: rent().minimum_balance(data.len()),
+            data,
+            owner: token_program,
+            executable: false,
+        });
+    }
+
+    pub fn create_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey, token_program: Pubkey, amount: u64) {
+        let account = spl_token::state::Account {
+            mint,
+            owner,
+            amount,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        };
+        let mut data = vec![0; spl_token::state::Account::LEN];
+        spl_token::state::Account::pack(account, &mut data).unwrap();
+        self.set_account(key, AccountState {
+            lamports: rent().minimum_balance(data.len()),
+            data,
+            owner: token_program,
+            executable: false,
+        });
+    }
+
+    /// Creates `wallet`'s associated token account for `mint` holding `amount`
+    pub fn create_ata(&mut self, wallet: Pubkey, mint: Pubkey, amount: u64) -> Pubkey {
+        let token_program = self.account(&mint).expect("mint exists").owner;
+        let ata = get_associated_token_address_with_program_id(&wallet, &mint, &token_program);
+        self.create_token_account(ata, mint, wallet, token_program, amount);
+        ata
+    }
+
+    pub fn ata(&self, wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+        let token_program = self.account(mint).expect("mint exists").owner;
+        get_associated_token_address_with_program_id(wallet, mint, &token_program)
+    }
+
+    pub fn token_account(&self, key: &Pubkey) -> spl_token::state::Account {
+        let account = self.accounts.get(key).unwrap_or_else(|| panic!("no token account at {key}"));
+        spl_token::state::Account::unpack(&account.data).unwrap()
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        self.token_account(key).amount
+    }
+
+    pub fn mint_supply(&self, mint: &Pubkey) -> u64 {
+        spl_token::state::Mint::unpack(&self.accounts[mint].data).unwrap().supply
+    }
+
+    // --- Program fixtures ---
+
+    /// Onboarded user with its three ATAs, `usdc` of which is funded
+    pub fn create_user(&mut self, user_id: &str, usdc: u64) -> Pubkey {
+        let (user, bump) = pda::user(user_id);
+        let treasury = pda::treasury().0;
+        let (usdc_mint, cnctd_mint, music_mint) = (self.usdc_mint, self.cnctd_mint, self.music_mint);
+
+        let usdc_ata = self.create_ata(user, usdc_mint, usdc);
+        let cnctd_ata = self.create_ata(user, cnctd_mint, 0);
+        let music_ata = self.create_ata(user, music_mint, 0);
+
+        self.set_state(user, &UserPDA {
+            admin: treasury,
+            auth: None,
+            usdc_ata,
+            usdc_cust: None,
+            cnctd_ata,
+            cnctd_cust: None,
+            music_ata,
+            music_cust: None,
+            fees_waived: 0,
+            waived_count: 0,
+            bump,
+            version: UserPDA::VERSION,
+        }, UserPDA::space());
+
+        user
+    }
+
+    /// Active release priced in USDC, paying each recipient's share at its ATAs
+    pub fn create_release(&mut self, release_id: &str, price: u64, treasury_fee_bps: u16, shares: &[(SplitRecipient, u16)]) -> Pubkey {
+        let (release, bump) = pda::release(release_id);
+        let split_shares = shares
+            .iter()
+            .map(|(recipient, share_bps)| {
+                let owner = match recipient {
+                    SplitRecipient::User { user_pda } => *user_pda,
+                    SplitRecipient::Band { band_pda } => *band_pda,
+                    SplitRecipient::External => panic!("external recipients have no derived ATAs"),
+                };
+                SplitShare {
+                    recipient_usdc_ata: self.ata(&owner, &self.usdc_mint),
+                    recipient_cnctd_ata: self.ata(&owner, &self.cnctd_mint),
+                    share_bps: *share_bps,
+                    recipient: recipient.clone(),
+                }
+            })
+            .collect();
+
+        self.set_state(release, &Release {
+            release_id: release_id.to_string(),
+            authority: self.operator,
+            price,
+            currency_mint: self.usdc_mint,
+            treasury_fee_bps,
+            split_shares,
+            active: true,
+            created_at: START_TIME,
+            updated_at: START_TIME,
+            bump,
+        }, Release::space());
+
+        release
+    }
+
+    /// Band paying each `(user_pda, share_bps)`, with USDC and CNCTD ATAs holding the given balances
+    pub fn create_band(&mut self, band_id: &str, members: &[(Pubkey, u16)], usdc: u64, cnctd: u64) -> Pubkey {
+        let (band, bump) = pda::band(band_id);
+        let (usdc_mint, cnctd_mint) = (self.usdc_mint, self.cnctd_mint);
+
+        let usdc_ata = self.create_ata(band, usdc_mint, usdc);
+        let cnctd_ata = self.create_ata(band, cnctd_mint, cnctd);
+
+        self.set_state(band, &BandPDA {
+            admin: pda::treasury().0,
+            usdc_ata,
+            cnctd_ata,
+            fees_waived: 0,
+            waived_count: 0,
+            bump,
+            version: BandPDA::VERSION,
+            members: members.iter().map(|(user_pda, share_bps)| BandMember { user_pda: *user_pda, share_bps: *share_bps }).collect(),
+            release_refs: 0,
+        }, BandPDA::space());
+
+        band
+    }
+
+    pub fn pause(&mut self, mask: u8) {
+        self.update_state(pda::program_config().0, |config: &mut ProgramConfig| config.paused |= mask);
+    }
+
+    pub fn set_reimbursement_limits(&mut self, max_per_tx: u64, max_per_day: u64) {
+        self.update_state(pda::reimbursement_config().0, |config: &mut ReimbursementConfig| {
+            config.max_per_tx = max_per_tx;
+            config.max_per_day = max_per_day;
+        });
+    }
+
+    // --- Execution ---
+
+    /// Builds an instruction for this program from its Anchor accounts and instruction data
+    pub fn instruction(accounts: impl ToAccountMetas, data: impl InstructionData, remaining: Vec<AccountMeta>) -> Instruction {
+        let mut metas = accounts.to_account_metas(None);
+        metas.extend(remaining);
+        Instruction { program_id: cnctd_studio_program::ID, accounts: metas, data: data.data() }
+    }
+
+    /// Runs `instruction` signed by `signers`. Like a transaction, nothing is kept if it fails.
+    pub fn process(&mut self, instruction: &Instruction, signers: &[Pubkey]) -> std::result::Result<(), ProgramError> {
+        for meta in &instruction.accounts {
+            if meta.is_signer {
+                assert!(signers.contains(&meta.pubkey), "{} must sign", meta.pubkey);
+            }
+        }
+
+        // Unique accounts in order of first use, with their merged privileges
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut privileges: HashMap<Pubkey, (bool, bool)> = HashMap::new();
+        for meta in &instruction.accounts {
+            let entry = privileges.entry(meta.pubkey).or_insert_with(|| {
+                keys.push(meta.pubkey);
+                (false, false)
+            });
+            entry.0 |= meta.is_signer && signers.contains(&meta.pubkey);
+            entry.1 |= meta.is_writable;
+        }
+
+        let pre: HashMap<Pubkey, AccountState> = keys
+            .iter()
+            .map(|key| (*key, self.accounts.get(key).cloned().unwrap_or_else(system_account)))
+            .collect();
+
+        let mut input = serialize_input(instruction, &keys, &privileges, &pre);
+        let post = {
+            let (program_id, account_infos, data) = unsafe { deserialize(input.as_mut_ptr() as *mut u8) };
+            cnctd_studio_program::entry(program_id, &account_infos, data)?;
+
+            keys.iter()
+                .map(|key| {
+                    let info = account_infos.iter().find(|info| info.key == key).unwrap();
+                    (*key, AccountState {
+                        lamports: info.lamports(),
+                        data: info.data.borrow().to_vec(),
+                        owner: *info.owner,
+                        executable: info.executable,
+                    })
+                })
+                .collect::<HashMap<_, _>>()
+        };
+
+        // The runtime's checks on the instruction as a whole
+        let pre_total: u128 = pre.values().map(|account| account.lamports as u128).sum();
+        let post_total: u128 = post.values().map(|account| account.lamports as u128).sum();
+        if pre_total != post_total {
+            println!("Instruction changed total lamports from {pre_total} to {post_total}");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        for key in &keys {
+            let (before, after) = (&pre[key], &post[key]);
+            let writable = privileges[key].1;
+            if !writable && before != after {
+                println!("Read-only account {key} modified");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if writable && after.lamports > 0 && after.lamports < rent().minimum_balance(after.data.len()) {
+                println!("Account {key} left below rent exemption");
+                return Err(ProgramError::AccountNotRentExempt);
+            }
+        }
+
+        for (key, account) in post {
+            if account.lamports == 0 {
+                self.accounts.remove(&key);
+            } else {
+                self.accounts.insert(key, account);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn system_account() -> AccountState {
+    AccountState { lamports: 0, data: Vec::new(), owner: system_program::ID, executable: false }
+}
+
+fn rent_sysvar_data() -> Vec<u8> {
+    let rent = rent();
+    let mut data = Vec::new();
+    data.extend_from_slice(&rent.lamports_per_byte_year.to_le_bytes());
+    data.extend_from_slice(&rent.exemption_threshold.to_le_bytes());
+    data.push(rent.burn_percent);
+    data
+}
+
+/// Lays the accounts out the way the loader does, so the entrypoint deserializes them in place
+/// and reallocation has the usual headroom
+fn serialize_input(
+    instruction: &Instruction,
+    keys: &[Pubkey],
+    privileges: &HashMap<Pubkey, (bool, bool)>,
+    accounts: &HashMap<Pubkey, AccountState>,
+) -> Vec<u64> {
+    let mut buffer: Vec<u8> = Vec::new();
+    buffer.extend_from_slice(&(instruction.accounts.len() as u64).to_le_bytes());
+
+    let mut positions: HashMap<Pubkey, usize> = HashMap::new();
+    for (index, meta) in instruction.accounts.iter().enumerate() {
+        if let Some(position) = positions.get(&meta.pubkey) {
+            buffer.push(*position as u8);
+            buffer.extend_from_slice(&[0; 7]);
+            continue;
+        }
+        positions.insert(meta.pubkey, index);
+
+        let account = &accounts[&meta.pubkey];
+        let (is_signer, is_writable) = privileges[&meta.pubkey];
+        buffer.push(NON_DUP_MARKER);
+        buffer.push(is_signer as u8);
+        buffer.push(is_writable as u8);
+        buffer.push(account.executable as u8);
+        buffer.extend_from_slice(&[0; 4]);
+        buffer.extend_from_slice(meta.pubkey.as_ref());
+        buffer.extend_from_slice(account.owner.as_ref());
+        buffer.extend_from_slice(&account.lamports.to_le_bytes());
+        buffer.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(&account.data);
+        buffer.resize(buffer.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+        buffer.resize(buffer.len().next_multiple_of(8), 0);
+        buffer.extend_from_slice(&u64::MAX.to_le_bytes()); // rent epoch
+    }
+    assert_eq!(keys.len(), positions.len());
+
+    buffer.extend_from_slice(&(instruction.data.len() as u64).to_le_bytes());
+    buffer.extend_from_slice(&instruction.data);
+    buffer.extend_from_slice(instruction.program_id.as_ref());
+
+    // u64 words keep the buffer aligned the way the entrypoint expects
+    let mut words = vec![0u64; buffer.len().div_ceil(size_of::<u64>())];
+    unsafe {
+        std::ptr::copy_nonoverlapping(buffer.as_ptr(), words.as_mut_ptr() as *mut u8, buffer.len());
+    }
+    words
+}
+
+// --- Runtime stubs ---
+
+fn install_stubs() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(Runtime));
+    });
+}
+
+struct Runtime;
+
+impl SyscallStubs for Runtime {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { unix_timestamp: NOW.with(|now| now.get()), ..Clock::default() };
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Clock, clock) };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { std::ptr::write_unaligned(var_addr as *mut Rent, rent()) };
+        0
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> std::result::Result<(), ProgramError> {
+        let signers: Vec<Pubkey> = signers_seeds
+            .iter()
+            .map(|seeds| Pubkey::create_program_address(seeds, &cnctd_studio_program::ID))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| ProgramError::InvalidSeeds)?;
+        let cpi = Cpi { instruction, account_infos, signers };
+
+        match instruction.program_id {
+            id if id == system_program::ID => cpi.system(),
+            id if id == token::ID || id == token_2022::ID => cpi.token(),
+            id if id == associated_token::ID => cpi.associated_token(),
+            id => {
+                msg!("CPI to unsupported program {}", id);
+                Err(ProgramError::IncorrectProgramId)
+            }
+        }
+    }
+}
+
+struct Cpi<'a, 'info> {
+    instruction: &'a Instruction,
+    account_infos: &'a [AccountInfo<'info>],
+    signers: Vec<Pubkey>,
+}
+
+impl<'info> Cpi<'_, 'info> {
+    fn account(&self, index: usize) -> std::result::Result<&AccountInfo<'info>, ProgramError> {
+        let meta = self.instruction.accounts.get(index).ok_or(ProgramError::NotEnoughAccountKeys)?;
+        self.account_infos
+            .iter()
+            .find(|info| *info.key == meta.pubkey)
+            .ok_or(ProgramError::NotEnoughAccountKeys)
+    }
+
+    fn writable(&self, index: usize) -> std::result::Result<&AccountInfo<'info>, ProgramError> {
+        let info = self.account(index)?;
+        if !info.is_writable {
+            msg!("CPI writes to read-only account {}", info.key);
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(info)
+    }
+
+    fn require_signer(&self, info: &AccountInfo) -> std::result::Result<(), ProgramError> {
+        if info.is_signer || self.signers.contains(info.key) {
+            Ok(())
+        } else {
+            msg!("CPI missing signature of {}", info.key);
+            Err(ProgramError::MissingRequiredSignature)
+        }
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.instruction.data
+    }
+
+    fn u64_at(&self, offset: usize) -> std::result::Result<u64, ProgramError> {
+        self.data()
+            .get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+
+    fn system(&self) -> std::result::Result<(), ProgramError> {
+        let tag = u32::from_le_bytes(self.data().get(..4).ok_or(ProgramError::InvalidInstructionData)?.try_into().unwrap());
+        match tag {
+            // CreateAccount { lamports, space, owner }
+            0 => {
+                let (from, to) = (self.writable(0)?, self.writable(1)?);
+                self.require_signer(from)?;
+                self.require_signer(to)?;
+                if to.lamports() > 0 || !to.data_is_empty() || *to.owner != system_program::ID {
+                    return Err(ProgramError::AccountAlreadyInitialized);
+                }
+                let (lamports, space) = (self.u64_at(4)?, self.u64_at(12)?);
+                let owner = Pubkey::try_from(&self.data()[20..52]).unwrap();
+                move_lamports(from, to, lamports)?;
+                to.realloc(space as usize, true)?;
+                to.assign(&owner);
+                Ok(())
+            }
+            // Assign { owner }
+            1 => {
+                let account = self.writable(0)?;
+                self.require_signer(account)?;
+                account.assign(&Pubkey::try_from(&self.data()[4..36]).unwrap());
+                Ok(())
+            }
+            // Transfer { lamports }
+            2 => {
+                let (from, to) = (self.writable(0)?, self.writable(1)?);
+                self.require_signer(from)?;
+                if *from.owner != system_program::ID || !from.data_is_empty() {
+                    return Err(ProgramError::InvalidArgument);
+                }
+                move_lamports(from, to, self.u64_at(4)?)
+            }
+            // Allocate { space }
+            8 => {
+                let account = self.writable(0)?;
+                self.require_signer(account)?;
+                if *account.owner != system_program::ID || !account.data_is_empty() {
+                    return Err(ProgramError::AccountAlreadyInitialized);
+                }
+                account.realloc(self.u64_at(4)? as usize, true)
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    fn token(&self) -> std::result::Result<(), ProgramError> {
+        let program = self.instruction.program_id;
+        let owned = |info: &AccountInfo| match *info.owner == program {
+            true => Ok(()),
+            false => Err(ProgramError::IncorrectProgramId),
+        };
+
+        match self.data().first().copied().ok_or(ProgramError::InvalidInstructionData)? {
+            // Transfer { amount } and TransferChecked { amount, decimals }
+            tag @ (3 | 12) => {
+                let checked = tag == 12;
+                let (source, destination, authority) = match checked {
+                    true => (self.writable(0)?, self.writable(2)?, self.account(3)?),
+                    false => (self.writable(0)?, self.writable(1)?, self.account(2)?),
+                };
+                owned(source)?;
+                owned(destination)?;
+                let amount = self.u64_at(1)?;
+
+                let mut from = unpack_account(source)?;
+                let mut to = unpack_account(destination)?;
+                if from.mint != to.mint {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                if checked {
+                    let mint = self.account(1)?;
+                    owned(mint)?;
+                    if *mint.key != from.mint || unpack_mint(mint)?.decimals != self.data()[9] {
+                        return Err(ProgramError::InvalidAccountData);
+                    }
+                }
+                if from.owner != *authority.key {
+                    msg!("Token transfer authority {} does not own {}", authority.key, source.key);
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                self.require_signer(authority)?;
+
+                from.amount = from.amount.checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+                if source.key == destination.key {
+                    return Ok(());
+                }
+                to.amount = to.amount.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+                pack_account(source, from)?;
+                pack_account(destination, to)
+            }
+            // MintTo { amount } and MintToChecked { amount, decimals }
+            7 | 14 => {
+                let (mint_info, destination, authority) = (self.writable(0)?, self.writable(1)?, self.account(2)?);
+                owned(mint_info)?;
+                owned(destination)?;
+
+                let mut mint = unpack_mint(mint_info)?;
+                let mut to = unpack_account(destination)?;
+                if to.mint != *mint_info.key || mint.mint_authority != COption::Some(*authority.key) {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                self.require_signer(authority)?;
+
+                let amount = self.u64_at(1)?;
+                mint.supply = mint.supply.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+                to.amount = to.amount.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+                pack_mint(mint_info, mint)?;
+                pack_account(destination, to)
+            }
+            // CloseAccount
+            9 => {
+                let (account, destination, authority) = (self.writable(0)?, self.writable(1)?, self.account(2)?);
+                owned(account)?;
+                let state = unpack_account(account)?;
+                if state.amount > 0 {
+                    msg!("Token account {} is not empty", account.key);
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                if state.owner != *authority.key {
+                    return Err(ProgramError::InvalidAccountData);
+                }
+                self.require_signer(authority)?;
+
+                move_lamports(account, destination, account.lamports())?;
+                account.realloc(0, false)?;
+                account.assign(&system_program::ID);
+                Ok(())
+            }
+            tag => {
+                msg!("Unsupported token instruction {}", tag);
+                Err(ProgramError::InvalidInstructionData)
+            }
+        }
+    }
+
+    fn associated_token(&self) -> std::result::Result<(), ProgramError> {
+        let idempotent = self.data().first() == Some(&1);
+        let (payer, ata) = (self.writable(0)?, self.writable(1)?);
+        let (wallet, mint, token_program) = (self.account(2)?, self.account(3)?, self.account(5)?);
+
+        if *ata.key != get_associated_token_address_with_program_id(wallet.key, mint.key, token_program.key) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+        if *ata.owner == *token_program.key {
+            return match idempotent {
+                true => Ok(()),
+                false => Err(ProgramError::AccountAlreadyInitialized),
+            };
+        }
+        self.require_signer(payer)?;
+
+        let space = spl_token::state::Account::LEN;
+        let needed = rent().minimum_balance(space).saturating_sub(ata.lamports());
+        move_lamports(payer, ata, needed)?;
+        ata.realloc(space, true)?;
+        ata.assign(token_program.key);
+        pack_account(ata, spl_token::state::Account {
+            mint: *mint.key,
+            owner: *wallet.key,
+            amount: 0,
+            delegate: COption::None,
+            state: spl_token::state::AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        })
+    }
+}
+
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, lamports: u64) -> std::result::Result<(), ProgramError> {
+    let remaining = from.lamports().checked_sub(lamports).ok_or(ProgramError::InsufficientFunds)?;
+    **from.try_borrow_mut_lamports()? = remaining;
+    **to.try_borrow_mut_lamports()? += lamports;
+    Ok(())
+}
+
+fn unpack_account(info: &AccountInfo) -> std::result::Result<spl_token::state::Account, ProgramError> {
+    spl_token::state::Account::unpack(&info.try_borrow_data()?)
+}
+
+fn pack_account(info: &AccountInfo, account: spl_token::state::Account) -> std::result::Result<(), ProgramError> {
+    spl_token::state::Account::pack(account, &mut info.try_borrow_mut_data()?)
+}
+
+fn unpack_mint(info: &AccountInfo) -> std::result::Result<spl_token::state::Mint, ProgramError> {
+    spl_token::state::Mint::unpack(&info.try_borrow_data()?)
+}
+
+fn pack_mint(info: &AccountInfo, mint: spl_token::state::Mint) -> std::result::Result<(), ProgramError> {
+    spl_token::state::Mint::pack(mint, &mut info.try_borrow_mut_data()?)
+}
+
+// --- Shorthands ---
+
+/// Fresh well-formed UUID for release, user and band ids
+pub fn uuid() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static NEXT: AtomicU64 = AtomicU64::new(1);
+    format!("{:032x}", NEXT.fetch_add(1, Ordering::Relaxed)).from_solana_seed_format()
+}
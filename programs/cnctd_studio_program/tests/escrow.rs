@@ -0,0 +1,250 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program, sysvar},
+};
+use anchor_spl::{associated_token, token};
+use cnctd_studio_program::{
+    accounts, instruction,
+    arguments::release::{OpenEscrowArgs, ReclaimExpiredEscrowArgs, RefundEscrowArgs, SplitRecipient},
+    constants::DEFAULT_ESCROW_FULFILLMENT_WINDOW,
+    errors::CnctdStudioError,
+    pda,
+    state::{program_config::PauseSubsystem, reimbursement::AdminUsage, release_escrow::ReleaseEscrow},
+};
+use common::{program_error, rent, uuid, TestEnv};
+
+const PRICE: u64 = 10_000_000;
+
+struct Purchase {
+    release_id: String,
+    buyer_id: String,
+    buyer: Pubkey,
+    escrow: Pubkey,
+}
+
+impl Purchase {
+    fn new(env: &mut TestEnv) -> Self {
+        let (release_id, buyer_id, artist_id) = (uuid(), uuid(), uuid());
+        let buyer = env.create_user(&buyer_id, PRICE);
+        let artist = env.create_user(&artist_id, 0);
+        env.create_release(&release_id, PRICE, 1_000, &[(SplitRecipient::User { user_pda: artist }, 10_000)]);
+        let escrow = pda::release_escrow(&release_id, &buyer_id).0;
+
+        Purchase { release_id, buyer_id, buyer, escrow }
+    }
+
+    fn open(&self, env: &TestEnv, fee_compensation: Option<u64>) -> Instruction {
+        TestEnv::instruction(
+            accounts::OpenEscrow {
+                admin: env.operator,
+                buyer: self.buyer,
+                buyer_usdc_ata: env.ata(&self.buyer, &env.usdc_mint),
+                treasury: pda::treasury().0,
+                program_config: pda::program_config().0,
+                reimbursement_config: pda::reimbursement_config().0,
+                admin_usage: pda::admin_usage(&env.operator).0,
+                release: pda::release(&self.release_id).0,
+                usdc_mint: env.usdc_mint,
+                escrow: self.escrow,
+                legacy_escrow: pda::legacy_release_escrow(&self.release_id, &self.buyer_id).0,
+                escrow_usdc_ata: env.ata(&self.escrow, &env.usdc_mint),
+                token_program: token::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::OpenReleaseEscrow {
+                args: OpenEscrowArgs {
+                    buyer_id: self.buyer_id.clone(),
+                    release_id: self.release_id.clone(),
+                    purchase_date: env.now(),
+                    fulfillment_window: None,
+                    fee_compensation,
+                },
+            },
+            vec![],
+        )
+    }
+
+    fn refund(&self, env: &TestEnv, admin: Pubkey) -> Instruction {
+        TestEnv::instruction(
+            accounts::RefundEscrow {
+                admin,
+                buyer: self.buyer,
+                buyer_usdc_ata: env.ata(&self.buyer, &env.usdc_mint),
+                treasury: pda::treasury().0,
+                program_metadata: pda::program_metadata().0,
+                reimbursement_config: pda::reimbursement_config().0,
+                admin_usage: pda::admin_usage(&admin).0,
+                usdc_mint: env.usdc_mint,
+                escrow: self.escrow,
+                escrow_usdc_ata: env.ata(&self.escrow, &env.usdc_mint),
+                token_program: token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::RefundReleaseEscrow {
+                args: RefundEscrowArgs {
+                    buyer_id: self.buyer_id.clone(),
+                    release_id: self.release_id.clone(),
+                    fee_compensation: None,
+                },
+            },
+            vec![],
+        )
+    }
+
+    fn reclaim(&self, env: &TestEnv, cranker: Pubkey) -> Instruction {
+        TestEnv::instruction(
+            accounts::ReclaimExpiredEscrow {
+                cranker,
+                buyer: self.buyer,
+                buyer_usdc_ata: env.ata(&self.buyer, &env.usdc_mint),
+                treasury: pda::treasury().0,
+                program_metadata: pda::program_metadata().0,
+                usdc_mint: env.usdc_mint,
+                escrow: self.escrow,
+                escrow_usdc_ata: env.ata(&self.escrow, &env.usdc_mint),
+                token_program: token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::ReclaimExpiredEscrow {
+                args: ReclaimExpiredEscrowArgs {
+                    buyer_id: self.buyer_id.clone(),
+                    release_id: self.release_id.clone(),
+                },
+            },
+            vec![],
+        )
+    }
+}
+
+fn open_escrow_rent() -> u64 {
+    rent().minimum_balance(ReleaseEscrow::space()) + rent().minimum_balance(165)
+}
+
+#[test]
+fn open_escrow_holds_price_and_reimburses_rent() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    let operator_before = env.lamports(&env.operator);
+
+    env.process(&purchase.open(&env, Some(5_000)), &[env.operator]).unwrap();
+
+    let escrow: ReleaseEscrow = env.state(&purchase.escrow);
+    assert_eq!(escrow.total_amount, PRICE);
+    assert_eq!(escrow.treasury_fee, PRICE / 10);
+    assert_eq!(escrow.payment_splits[0].amount, PRICE - PRICE / 10);
+    assert_eq!(escrow.expires_at, env.now() + DEFAULT_ESCROW_FULFILLMENT_WINDOW);
+    assert_eq!(env.token_balance(&env.ata(&purchase.escrow, &env.usdc_mint)), PRICE);
+    assert_eq!(env.token_balance(&env.ata(&purchase.buyer, &env.usdc_mint)), 0);
+
+    // The operator paid for the escrow, its token account and its usage record, and got the
+    // first two back along with the fee compensation
+    let usage_rent = rent().minimum_balance(AdminUsage::space());
+    assert_eq!(env.lamports(&env.operator), operator_before - usage_rent + 5_000);
+    let usage: AdminUsage = env.state(&pda::admin_usage(&env.operator).0);
+    assert_eq!(usage.reimbursed_today, open_escrow_rent() + 5_000);
+}
+
+#[test]
+fn reopening_a_funded_escrow_reimburses_only_the_fee() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    env.process(&purchase.open(&env, None), &[env.operator]).unwrap();
+    let operator_before = env.lamports(&env.operator);
+    let treasury_before = env.lamports(&pda::treasury().0);
+
+    env.process(&purchase.open(&env, Some(5_000)), &[env.operator]).unwrap();
+
+    assert_eq!(env.lamports(&env.operator), operator_before + 5_000);
+    assert_eq!(env.lamports(&pda::treasury().0), treasury_before - 5_000);
+    assert_eq!(env.token_balance(&env.ata(&purchase.escrow, &env.usdc_mint)), PRICE);
+}
+
+#[test]
+fn open_escrow_respects_reimbursement_limits() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+
+    // Rent alone is over the per-transaction ceiling
+    env.set_reimbursement_limits(open_escrow_rent() - 1, 0);
+    assert_eq!(
+        env.process(&purchase.open(&env, None), &[env.operator]),
+        Err(program_error(CnctdStudioError::ReimbursementLimitExceeded))
+    );
+
+    // A second escrow the same day goes over the daily ceiling
+    env.set_reimbursement_limits(0, open_escrow_rent() + 1);
+    env.process(&purchase.open(&env, None), &[env.operator]).unwrap();
+    let second = Purchase::new(&mut env);
+    assert_eq!(
+        env.process(&second.open(&env, None), &[env.operator]),
+        Err(program_error(CnctdStudioError::ReimbursementLimitExceeded))
+    );
+
+    // The daily allowance starts over the next day
+    env.advance_time(24 * 60 * 60);
+    env.process(&second.open(&env, None), &[env.operator]).unwrap();
+}
+
+#[test]
+fn open_escrow_is_blocked_while_purchases_are_paused() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    env.pause(PauseSubsystem::Purchases.mask());
+
+    assert_eq!(
+        env.process(&purchase.open(&env, None), &[env.operator]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+    assert!(!env.exists(&purchase.escrow));
+}
+
+#[test]
+fn refund_returns_payment_and_rent() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    env.process(&purchase.open(&env, None), &[env.operator]).unwrap();
+    let treasury_before = env.lamports(&pda::treasury().0);
+
+    env.process(&purchase.refund(&env, env.operator), &[env.operator]).unwrap();
+
+    assert_eq!(env.token_balance(&env.ata(&purchase.buyer, &env.usdc_mint)), PRICE);
+    assert!(!env.exists(&purchase.escrow));
+    assert!(!env.exists(&env.ata(&purchase.escrow, &env.usdc_mint)));
+    assert_eq!(env.lamports(&pda::treasury().0), treasury_before + open_escrow_rent());
+}
+
+#[test]
+fn refund_requires_an_operator() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    env.process(&purchase.open(&env, None), &[env.operator]).unwrap();
+
+    assert_eq!(
+        env.process(&purchase.refund(&env, env.treasurer), &[env.treasurer]),
+        Err(program_error(CnctdStudioError::Unauthorized))
+    );
+}
+
+#[test]
+fn expired_escrow_can_be_reclaimed_by_anyone() {
+    let mut env = TestEnv::new();
+    let purchase = Purchase::new(&mut env);
+    env.process(&purchase.open(&env, None), &[env.operator]).unwrap();
+    let cranker = Pubkey::new_unique();
+    env.fund(&cranker, common::SOL);
+
+    assert_eq!(
+        env.process(&purchase.reclaim(&env, cranker), &[cranker]),
+        Err(program_error(CnctdStudioError::EscrowNotExpired))
+    );
+
+    env.advance_time(DEFAULT_ESCROW_FULFILLMENT_WINDOW + 1);
+    env.process(&purchase.reclaim(&env, cranker), &[cranker]).unwrap();
+
+    assert_eq!(env.token_balance(&env.ata(&purchase.buyer, &env.usdc_mint)), PRICE);
+    assert!(!env.exists(&purchase.escrow));
+}
@@ -0,0 +1,206 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program},
+};
+use anchor_spl::{token, token_2022};
+use cnctd_studio_program::{
+    accounts, instruction,
+    errors::CnctdStudioError,
+    pda,
+    state::{
+        governance::{Governance, Proposal, ProposalAction},
+        program_config::{PauseSubsystem, ProgramConfig},
+        treasury::{AdminRole, AdminRoles, Treasury},
+    },
+};
+use common::{program_error, TestEnv, SOL};
+
+const DELAY: i64 = 60 * 60;
+
+/// Adds an admin holding `role` and returns their key
+fn add_admin(env: &mut TestEnv, role: AdminRole) -> Pubkey {
+    let admin = Pubkey::new_unique();
+    env.fund(&admin, SOL);
+    env.update_state(pda::treasury().0, |treasury: &mut Treasury| {
+        treasury.admins.push(admin);
+        treasury.roles.push(AdminRoles { admin, roles: role.mask() });
+    });
+    admin
+}
+
+fn configure_governance(env: &mut TestEnv, approval_threshold: u8, admin_change_delay: i64) {
+    env.update_state(pda::governance().0, |governance: &mut Governance| {
+        governance.approval_threshold = approval_threshold;
+        governance.admin_change_delay = admin_change_delay;
+    });
+}
+
+/// Proposes `action` as `proposer` and returns the proposal's address
+fn propose(env: &mut TestEnv, proposer: Pubkey, action: ProposalAction) -> Pubkey {
+    let id = env.state::<Governance>(&pda::governance().0).proposal_count;
+    let proposal = pda::proposal(id).0;
+    let create = TestEnv::instruction(
+        accounts::CreateProposal {
+            proposer,
+            treasury: pda::treasury().0,
+            governance: pda::governance().0,
+            proposal,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProposal { action },
+        vec![],
+    );
+    env.process(&create, &[proposer]).unwrap();
+    proposal
+}
+
+fn approve(proposal: Pubkey, approver: Pubkey) -> Instruction {
+    TestEnv::instruction(
+        accounts::ApproveProposal {
+            approver,
+            treasury: pda::treasury().0,
+            governance: pda::governance().0,
+            proposal,
+        },
+        instruction::ApproveProposal {},
+        vec![],
+    )
+}
+
+fn execute(proposal: Pubkey, executor: Pubkey, tokens: Option<(Pubkey, Pubkey, Pubkey)>) -> Instruction {
+    TestEnv::instruction(
+        accounts::ExecuteProposal {
+            executor,
+            treasury: pda::treasury().0,
+            governance: pda::governance().0,
+            proposal,
+            program_config: pda::program_config().0,
+            program_metadata: pda::program_metadata().0,
+            mint: tokens.map(|(mint, _, _)| mint),
+            treasury_ata: tokens.map(|(_, source, _)| source),
+            destination: tokens.map(|(_, _, destination)| destination),
+            emission_budget: None,
+            user_pda: None,
+            token_program: token::ID,
+            token_2022_program: token_2022::ID,
+            system_program: system_program::ID,
+        },
+        instruction::ExecuteProposal {},
+        vec![],
+    )
+}
+
+#[test]
+fn timelocked_proposal_waits_for_the_admin_change_delay() {
+    let mut env = TestEnv::new();
+    configure_governance(&mut env, 1, DELAY);
+
+    let super_admin = env.super_admin;
+    let proposal = propose(&mut env, super_admin, ProposalAction::SetAdminChangeDelay { delay: 2 * DELAY });
+    assert_eq!(env.state::<Proposal>(&proposal).eta, env.now() + DELAY);
+
+    assert_eq!(
+        env.process(&execute(proposal, env.super_admin, None), &[env.super_admin]),
+        Err(program_error(CnctdStudioError::TimelockNotElapsed))
+    );
+
+    env.advance_time(DELAY);
+    env.process(&execute(proposal, env.super_admin, None), &[env.super_admin]).unwrap();
+
+    assert_eq!(env.state::<Governance>(&pda::governance().0).admin_change_delay, 2 * DELAY);
+    assert!(env.state::<Proposal>(&proposal).executed);
+    assert_eq!(
+        env.process(&execute(proposal, env.super_admin, None), &[env.super_admin]),
+        Err(program_error(CnctdStudioError::ProposalAlreadyExecuted))
+    );
+}
+
+#[test]
+fn treasury_transfer_needs_the_approval_threshold() {
+    let mut env = TestEnv::new();
+    let second_treasurer = add_admin(&mut env, AdminRole::Treasurer);
+    configure_governance(&mut env, 2, DELAY);
+
+    let treasury_ata = env.create_ata(pda::treasury().0, env.usdc_mint, 1_000);
+    let recipient = env.create_ata(Pubkey::new_unique(), env.usdc_mint, 0);
+    let tokens = Some((env.usdc_mint, treasury_ata, recipient));
+
+    // Token transfers aren't timelocked, only the approvals hold them back
+    let (treasurer, usdc_mint) = (env.treasurer, env.usdc_mint);
+    let proposal = propose(&mut env, treasurer, ProposalAction::TransferTokens {
+        mint: usdc_mint,
+        recipient_ata: recipient,
+        amount: 400,
+        standard: true,
+    });
+    assert_eq!(
+        env.process(&execute(proposal, env.treasurer, tokens), &[env.treasurer]),
+        Err(program_error(CnctdStudioError::InsufficientApprovals))
+    );
+
+    // Approvals only count from admins holding the role the action needs
+    assert_eq!(
+        env.process(&approve(proposal, env.operator), &[env.operator]),
+        Err(program_error(CnctdStudioError::Unauthorized))
+    );
+    env.process(&approve(proposal, second_treasurer), &[second_treasurer]).unwrap();
+    env.process(&execute(proposal, env.treasurer, tokens), &[env.treasurer]).unwrap();
+
+    assert_eq!(env.token_balance(&treasury_ata), 600);
+    assert_eq!(env.token_balance(&recipient), 400);
+}
+
+#[test]
+fn treasury_transfer_is_blocked_while_admin_ops_are_paused() {
+    let mut env = TestEnv::new();
+    let treasury_ata = env.create_ata(pda::treasury().0, env.usdc_mint, 1_000);
+    let recipient = env.create_ata(Pubkey::new_unique(), env.usdc_mint, 0);
+    let tokens = Some((env.usdc_mint, treasury_ata, recipient));
+
+    let (treasurer, usdc_mint) = (env.treasurer, env.usdc_mint);
+    let proposal = propose(&mut env, treasurer, ProposalAction::TransferTokens {
+        mint: usdc_mint,
+        recipient_ata: recipient,
+        amount: 400,
+        standard: true,
+    });
+    env.pause(PauseSubsystem::AdminOps.mask());
+
+    assert_eq!(
+        env.process(&execute(proposal, env.treasurer, tokens), &[env.treasurer]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+    assert_eq!(env.token_balance(&treasury_ata), 1_000);
+}
+
+#[test]
+fn guardian_and_super_admins_can_pause() {
+    let mut env = TestEnv::new();
+    let pause = |authority: Pubkey, subsystems: Vec<PauseSubsystem>| {
+        TestEnv::instruction(
+            accounts::Pause {
+                authority,
+                treasury: pda::treasury().0,
+                program_config: pda::program_config().0,
+            },
+            instruction::Pause { subsystems },
+            vec![],
+        )
+    };
+
+    assert_eq!(
+        env.process(&pause(env.operator, vec![PauseSubsystem::Purchases]), &[env.operator]),
+        Err(program_error(CnctdStudioError::Unauthorized))
+    );
+
+    env.process(&pause(env.guardian, vec![PauseSubsystem::Purchases]), &[env.guardian]).unwrap();
+    env.process(&pause(env.super_admin, vec![PauseSubsystem::Withdrawals]), &[env.super_admin]).unwrap();
+
+    let config: ProgramConfig = env.state(&pda::program_config().0);
+    assert!(config.is_paused(PauseSubsystem::Purchases));
+    assert!(config.is_paused(PauseSubsystem::Withdrawals));
+    assert!(!config.is_paused(PauseSubsystem::Rewards));
+    assert!(!config.is_paused(PauseSubsystem::AdminOps));
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program, sysvar},
+};
+use anchor_spl::{associated_token, token, token_2022};
+use cnctd_studio_program::{
+    accounts, instruction,
+    arguments::release::{PurchaseAccessArgs, SplitRecipient},
+    errors::CnctdStudioError,
+    pda,
+    state::{program_config::PauseSubsystem, reimbursement::AdminUsage, release_access::ReleaseAccess},
+};
+use common::{program_error, rent, uuid, TestEnv};
+
+// 10% fee leaves 900_003, which doesn't divide evenly between the 50/30/20 shares
+const PRICE: u64 = 1_000_003;
+const FEE: u64 = 100_000;
+const SHARES: [u16; 3] = [5_000, 3_000, 2_000];
+
+struct Sale {
+    release_id: String,
+    buyer_id: String,
+    buyer: Pubkey,
+    payees: Vec<Pubkey>, // UserPDA or BandPDA of each split share
+}
+
+impl Sale {
+    /// Release paying three artists the 50/30/20 shares
+    fn new(env: &mut TestEnv, buyer_usdc: u64) -> Self {
+        let shares: Vec<(SplitRecipient, u16)> = SHARES
+            .iter()
+            .map(|share_bps| (SplitRecipient::User { user_pda: env.create_user(&uuid(), 0) }, *share_bps))
+            .collect();
+        Self::paying(env, buyer_usdc, &shares)
+    }
+
+    fn paying(env: &mut TestEnv, buyer_usdc: u64, shares: &[(SplitRecipient, u16)]) -> Self {
+        let (release_id, buyer_id) = (uuid(), uuid());
+        let buyer = env.create_user(&buyer_id, buyer_usdc);
+        env.create_release(&release_id, PRICE, 1_000, shares);
+        let payees = shares
+            .iter()
+            .map(|(recipient, _)| match recipient {
+                SplitRecipient::User { user_pda } => *user_pda,
+                SplitRecipient::Band { band_pda } => *band_pda,
+                SplitRecipient::External => unreachable!(),
+            })
+            .collect();
+
+        Sale { release_id, buyer_id, buyer, payees }
+    }
+
+    fn release_access(&self) -> Pubkey {
+        pda::release_access(&self.release_id, &self.buyer_id).0
+    }
+
+    fn purchase(&self, env: &TestEnv, fee_compensation: Option<u64>) -> Instruction {
+        let remaining = [env.usdc_mint, env.cnctd_mint]
+            .iter()
+            .flat_map(|mint| self.payees.iter().map(|payee| AccountMeta::new(env.ata(payee, mint), false)))
+            .collect();
+
+        TestEnv::instruction(
+            accounts::PurchaseAccess {
+                admin: env.operator,
+                buyer: self.buyer,
+                buyer_usdc_ata: env.ata(&self.buyer, &env.usdc_mint),
+                buyer_music_ata: env.ata(&self.buyer, &env.music_mint),
+                treasury: pda::treasury().0,
+                program_metadata: pda::program_metadata().0,
+                program_config: pda::program_config().0,
+                reimbursement_config: pda::reimbursement_config().0,
+                admin_usage: pda::admin_usage(&env.operator).0,
+                reward_config: pda::reward_config().0,
+                treasury_usdc_ata: env.fee_recipient,
+                release_access: self.release_access(),
+                legacy_release_access: pda::legacy_release_access(&self.release_id, &self.buyer_id).0,
+                release: pda::release(&self.release_id).0,
+                usdc_mint: env.usdc_mint,
+                cnctd_mint: env.cnctd_mint,
+                music_mint: env.music_mint,
+                music_budget: pda::emission_budget(&env.music_mint).0,
+                cnctd_budget: pda::emission_budget(&env.cnctd_mint).0,
+                token_program: token::ID,
+                token_2022_program: token_2022::ID,
+                associated_token_program: associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::PurchaseReleaseAccess {
+                args: PurchaseAccessArgs {
+                    buyer_id: self.buyer_id.clone(),
+                    release_id: self.release_id.clone(),
+                    created_at: env.now(),
+                    expiration_date: None,
+                    fee_compensation,
+                },
+            },
+            remaining,
+        )
+    }
+
+    fn payee_balances(&self, env: &TestEnv, mint: &Pubkey) -> Vec<u64> {
+        self.payees.iter().map(|payee| env.token_balance(&env.ata(payee, mint))).collect()
+    }
+}
+
+#[test]
+fn purchase_splits_price_between_treasury_and_artists() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, PRICE);
+
+    env.process(&sale.purchase(&env, None), &[env.operator]).unwrap();
+
+    assert_eq!(env.token_balance(&env.ata(&sale.buyer, &env.usdc_mint)), 0);
+    assert_eq!(env.token_balance(&env.fee_recipient), FEE);
+    // Rounding dust goes to the largest remainders: 270_000.9 and 180_000.6 round up
+    assert_eq!(sale.payee_balances(&env, &env.usdc_mint), vec![450_001, 270_001, 180_001]);
+
+    let access: ReleaseAccess = env.state(&sale.release_access());
+    assert_eq!(access.release_id, sale.release_id);
+    assert_eq!(access.buyer_id, sale.buyer_id);
+}
+
+#[test]
+fn purchase_mints_rewards_at_the_configured_rates() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, PRICE);
+
+    env.process(&sale.purchase(&env, None), &[env.operator]).unwrap();
+
+    // Both rates are 100% of the price, the artist reward is shared by USDC payment
+    assert_eq!(env.token_balance(&env.ata(&sale.buyer, &env.music_mint)), PRICE);
+    let cnctd = sale.payee_balances(&env, &env.cnctd_mint);
+    assert_eq!(cnctd.iter().sum::<u64>(), PRICE);
+    assert!(cnctd[0] > cnctd[1] && cnctd[1] > cnctd[2]);
+    assert_eq!(env.mint_supply(&env.cnctd_mint), PRICE);
+}
+
+#[test]
+fn purchase_pays_a_band_share_into_the_band_account() {
+    let mut env = TestEnv::new();
+    let (member, artist) = (env.create_user(&uuid(), 0), env.create_user(&uuid(), 0));
+    let band = env.create_band(&uuid(), &[(member, 10_000)], 0, 0);
+    let sale = Sale::paying(&mut env, PRICE, &[
+        (SplitRecipient::Band { band_pda: band }, 6_000),
+        (SplitRecipient::User { user_pda: artist }, 4_000),
+    ]);
+
+    env.process(&sale.purchase(&env, None), &[env.operator]).unwrap();
+
+    // 60% of 900_003 is 540_001.8, the band takes the rounding dust
+    assert_eq!(sale.payee_balances(&env, &env.usdc_mint), vec![540_002, 360_001]);
+    assert_eq!(env.token_balance(&env.ata(&member, &env.usdc_mint)), 0);
+    assert_eq!(sale.payee_balances(&env, &env.cnctd_mint).iter().sum::<u64>(), PRICE);
+}
+
+#[test]
+fn purchase_pays_artists_without_rewards_while_rewards_are_paused() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, PRICE);
+    env.pause(PauseSubsystem::Rewards.mask());
+
+    env.process(&sale.purchase(&env, None), &[env.operator]).unwrap();
+
+    assert_eq!(sale.payee_balances(&env, &env.usdc_mint).iter().sum::<u64>(), PRICE - FEE);
+    assert_eq!(env.mint_supply(&env.music_mint), 0);
+    assert_eq!(env.mint_supply(&env.cnctd_mint), 0);
+}
+
+#[test]
+fn purchase_is_blocked_while_purchases_are_paused() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, PRICE);
+    env.pause(PauseSubsystem::Purchases.mask());
+
+    assert_eq!(
+        env.process(&sale.purchase(&env, None), &[env.operator]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+}
+
+#[test]
+fn purchase_rejects_a_substituted_payment_receiver() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, PRICE);
+    let thief = env.create_user(&uuid(), 0);
+
+    let mut purchase = sale.purchase(&env, None);
+    let first_receiver = purchase.accounts.len() - 2 * SHARES.len();
+    purchase.accounts[first_receiver].pubkey = env.ata(&thief, &env.usdc_mint);
+
+    assert_eq!(
+        env.process(&purchase, &[env.operator]),
+        Err(program_error(CnctdStudioError::InvalidPaymentReceiver))
+    );
+}
+
+#[test]
+fn repeat_purchase_reimburses_rent_only_once() {
+    let mut env = TestEnv::new();
+    let sale = Sale::new(&mut env, 2 * PRICE);
+    let usage = pda::admin_usage(&env.operator).0;
+    let access_rent = rent().minimum_balance(ReleaseAccess::space());
+
+    env.process(&sale.purchase(&env, Some(5_000)), &[env.operator]).unwrap();
+    assert_eq!(env.state::<AdminUsage>(&usage).reimbursed_today, access_rent + 5_000);
+
+    let operator_before = env.lamports(&env.operator);
+    env.process(&sale.purchase(&env, Some(5_000)), &[env.operator]).unwrap();
+
+    assert_eq!(env.lamports(&env.operator), operator_before + 5_000);
+    assert_eq!(env.state::<AdminUsage>(&usage).reimbursed_today, access_rent + 10_000);
+    assert_eq!(env.token_balance(&env.fee_recipient), 2 * FEE);
+}
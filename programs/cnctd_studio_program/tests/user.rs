@@ -0,0 +1,180 @@
+mod common;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, system_program},
+};
+use anchor_spl::{token, token_2022};
+use cnctd_studio_program::{
+    accounts, instruction,
+    arguments::withdraw_args::WithdrawArgs,
+    errors::CnctdStudioError,
+    pda,
+    state::{program_config::PauseSubsystem, user_pda::UserPDA},
+};
+use common::{program_error, rent, uuid, TestEnv};
+
+struct User {
+    user_id: String,
+    user: Pubkey,
+    wallet: Pubkey,
+    custody_usdc: Pubkey, // Token account of the wallet set as the user's USDC custody account
+}
+
+impl User {
+    /// User holding USDC and CNCTD, bound to a wallet with a USDC custody account
+    fn new(env: &mut TestEnv, usdc: u64, cnctd: u64) -> Self {
+        let user_id = uuid();
+        let user = env.create_user(&user_id, usdc);
+        let (wallet, custody_usdc) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (usdc_mint, cnctd_mint) = (env.usdc_mint, env.cnctd_mint);
+
+        env.create_token_account(custody_usdc, usdc_mint, wallet, token::ID, 0);
+        let cnctd_ata = env.ata(&user, &cnctd_mint);
+        env.create_token_account(cnctd_ata, cnctd_mint, user, token_2022::ID, cnctd);
+        env.update_state(user, |user_pda: &mut UserPDA| {
+            user_pda.auth = Some(wallet);
+            user_pda.usdc_cust = Some(custody_usdc);
+        });
+
+        User { user_id, user, wallet, custody_usdc }
+    }
+
+    fn close(&self, env: &TestEnv, usdc_destination: Option<Pubkey>, cnctd_destination: Option<Pubkey>) -> Instruction {
+        TestEnv::instruction(
+            accounts::CloseUserAccount {
+                user_pda: self.user,
+                treasury: pda::treasury().0,
+                program_config: pda::program_config().0,
+                admin: env.operator,
+                program_metadata: pda::program_metadata().0,
+                usdc_mint: env.usdc_mint,
+                cnctd_mint: env.cnctd_mint,
+                music_mint: env.music_mint,
+                usdc_ata: env.ata(&self.user, &env.usdc_mint),
+                cnctd_ata: env.ata(&self.user, &env.cnctd_mint),
+                music_ata: env.ata(&self.user, &env.music_mint),
+                usdc_destination,
+                cnctd_destination,
+                music_destination: None,
+                token_program: token::ID,
+                token_2022_program: token_2022::ID,
+                system_program: system_program::ID,
+            },
+            instruction::CloseUserAccount { user_id: self.user_id.clone() },
+            vec![],
+        )
+    }
+
+    fn withdraw(&self, env: &TestEnv, auth: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+        TestEnv::instruction(
+            accounts::Withdraw {
+                auth,
+                user_pda: self.user,
+                program_metadata: pda::program_metadata().0,
+                program_config: pda::program_config().0,
+                mint: env.usdc_mint,
+                source: env.ata(&self.user, &env.usdc_mint),
+                destination,
+                token_program: token::ID,
+            },
+            instruction::Withdraw { args: WithdrawArgs { user_id: self.user_id.clone(), amount } },
+            vec![],
+        )
+    }
+}
+
+#[test]
+fn close_sweeps_balances_and_returns_rent_to_the_treasury() {
+    let mut env = TestEnv::new();
+    let user = User::new(&mut env, 500, 20);
+    let (wallet, cnctd_mint) = (user.wallet, env.cnctd_mint);
+    let wallet_cnctd = env.create_ata(wallet, cnctd_mint, 0);
+
+    let treasury = pda::treasury().0;
+    let treasury_before = env.lamports(&treasury);
+    let reclaimed = env.lamports(&user.user)
+        + [env.usdc_mint, env.cnctd_mint, env.music_mint]
+            .iter()
+            .map(|mint| env.lamports(&env.ata(&user.user, mint)))
+            .sum::<u64>();
+
+    // USDC goes to the custody account, CNCTD to the bound wallet's ATA, MUSIC is empty
+    env.process(&user.close(&env, Some(user.custody_usdc), Some(wallet_cnctd)), &[env.operator]).unwrap();
+
+    assert_eq!(env.token_balance(&user.custody_usdc), 500);
+    assert_eq!(env.token_balance(&wallet_cnctd), 20);
+    assert!(!env.exists(&user.user));
+    for mint in [env.usdc_mint, env.cnctd_mint, env.music_mint] {
+        assert!(!env.exists(&env.ata(&user.user, &mint)));
+    }
+    assert_eq!(env.lamports(&treasury), treasury_before + reclaimed);
+    assert!(reclaimed > rent().minimum_balance(UserPDA::space()));
+}
+
+#[test]
+fn close_only_sweeps_to_the_users_own_accounts() {
+    let mut env = TestEnv::new();
+    let user = User::new(&mut env, 500, 0);
+    let usdc_mint = env.usdc_mint;
+    let elsewhere = env.create_ata(Pubkey::new_unique(), usdc_mint, 0);
+
+    assert_eq!(
+        env.process(&user.close(&env, Some(elsewhere), None), &[env.operator]),
+        Err(program_error(CnctdStudioError::CustodyDestinationRequired))
+    );
+    assert_eq!(
+        env.process(&user.close(&env, None, None), &[env.operator]),
+        Err(program_error(CnctdStudioError::TokenAccountNotEmpty))
+    );
+    assert_eq!(env.token_balance(&env.ata(&user.user, &env.usdc_mint)), 500);
+}
+
+#[test]
+fn close_is_blocked_while_withdrawals_are_paused() {
+    let mut env = TestEnv::new();
+    let user = User::new(&mut env, 500, 0);
+    env.pause(PauseSubsystem::Withdrawals.mask());
+
+    assert_eq!(
+        env.process(&user.close(&env, Some(user.custody_usdc), None), &[env.operator]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+    assert!(env.exists(&user.user));
+}
+
+#[test]
+fn withdraw_is_signed_by_the_bound_wallet() {
+    let mut env = TestEnv::new();
+    let user = User::new(&mut env, 500, 0);
+    let usdc_mint = env.usdc_mint;
+    let destination = env.create_ata(Pubkey::new_unique(), usdc_mint, 0);
+
+    let stranger = Pubkey::new_unique();
+    assert_eq!(
+        env.process(&user.withdraw(&env, stranger, destination, 200), &[stranger]),
+        Err(program_error(CnctdStudioError::Unauthorized))
+    );
+    assert_eq!(
+        env.process(&user.withdraw(&env, user.wallet, destination, 501), &[user.wallet]),
+        Err(program_error(CnctdStudioError::InsufficientFunds))
+    );
+
+    env.process(&user.withdraw(&env, user.wallet, destination, 200), &[user.wallet]).unwrap();
+
+    assert_eq!(env.token_balance(&destination), 200);
+    assert_eq!(env.token_balance(&env.ata(&user.user, &env.usdc_mint)), 300);
+}
+
+#[test]
+fn withdraw_is_blocked_while_withdrawals_are_paused() {
+    let mut env = TestEnv::new();
+    let user = User::new(&mut env, 500, 0);
+    env.pause(PauseSubsystem::Withdrawals.mask());
+
+    assert_eq!(
+        env.process(&user.withdraw(&env, user.wallet, user.custody_usdc, 200), &[user.wallet]),
+        Err(program_error(CnctdStudioError::ProgramPaused))
+    );
+    assert_eq!(env.token_balance(&env.ata(&user.user, &env.usdc_mint)), 500);
+}